@@ -0,0 +1,177 @@
+// This file is generated.
+// Any manual edits will be overwritten.
+
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(unused_variables)]
+#![allow(dead_code)]
+use crate::generated_api::TimeRange;
+use crate::json_rpc::{RpcRequest, RpcResponse};
+use crate::AnyError;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+/// Which of a customer's wallets a call applies to. Most bettors only ever
+/// have the UK wallet; the Australian wallet exists for AU-licensed
+/// customers only.
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Wallet {
+    UK,
+    AUSTRALIAN,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum AccountStatementIncludeItem {
+    ALL,
+    DEPOSITS_WITHDRAWALS,
+    EXCHANGE,
+    POKER_ROOM,
+}
+
+/// A customer's available-to-bet balance, exposure, and discount details.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AccountFundsResponse {
+    pub availableToBetBalance: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exposure: Option<f64>,
+    pub retainedCommission: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exposureLimit: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discountRate: Option<f64>,
+    pub pointsBalance: i64,
+}
+
+#[derive(Serialize)]
+pub struct getAccountFundsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet: Option<Wallet>,
+}
+
+pub fn getAccountFunds(
+    rb: RequestBuilder,
+    wallet: Option<Wallet>,
+) -> Result<AccountFundsResponse, AnyError> {
+    let req: getAccountFundsRequest = getAccountFundsRequest { wallet };
+    let rpc_request: RpcRequest<getAccountFundsRequest> =
+        RpcRequest::new("AccountAPING/v1.0/getAccountFunds".to_owned(), req);
+    let resp: RpcResponse<AccountFundsResponse> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
+}
+
+/// Details about the logged-in account itself, as opposed to its funds.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AccountDetailsResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currencyCode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firstName: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lastName: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localeCode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discountRate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pointsBalance: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countryCode: Option<String>,
+}
+
+pub fn getAccountDetails(rb: RequestBuilder) -> Result<AccountDetailsResponse, AnyError> {
+    let rpc_request: RpcRequest<()> =
+        RpcRequest::new("AccountAPING/v1.0/getAccountDetails".to_owned(), ());
+    let resp: RpcResponse<AccountDetailsResponse> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
+}
+
+/// A single line of an account statement.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StatementItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refId: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub itemDate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub itemClass: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AccountStatementReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accountStatement: Option<Vec<StatementItem>>,
+    pub moreAvailable: bool,
+}
+
+#[derive(Serialize)]
+pub struct getAccountStatementRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fromRecord: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recordCount: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub itemDateRange: Option<TimeRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub includeItem: Option<AccountStatementIncludeItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet: Option<Wallet>,
+}
+
+/// A single page of a date-ranged account statement query. The response's
+/// `moreAvailable` flag indicates whether a further call with an advanced
+/// `fromRecord` is needed to retrieve the rest.
+pub fn getAccountStatement(
+    rb: RequestBuilder,
+    locale: Option<String>,
+    fromRecord: Option<i32>,
+    recordCount: Option<i32>,
+    itemDateRange: Option<TimeRange>,
+    includeItem: Option<AccountStatementIncludeItem>,
+    wallet: Option<Wallet>,
+) -> Result<AccountStatementReport, AnyError> {
+    let req: getAccountStatementRequest = getAccountStatementRequest {
+        locale,
+        fromRecord,
+        recordCount,
+        itemDateRange,
+        includeItem,
+        wallet,
+    };
+    let rpc_request: RpcRequest<getAccountStatementRequest> =
+        RpcRequest::new("AccountAPING/v1.0/getAccountStatement".to_owned(), req);
+    let resp: RpcResponse<AccountStatementReport> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CurrencyRate {
+    pub currencyCode: String,
+    pub rate: f64,
+}
+
+#[derive(Serialize)]
+pub struct listCurrencyRatesRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fromCurrency: Option<String>,
+}
+
+pub fn listCurrencyRates(
+    rb: RequestBuilder,
+    fromCurrency: Option<String>,
+) -> Result<Vec<CurrencyRate>, AnyError> {
+    let req: listCurrencyRatesRequest = listCurrencyRatesRequest { fromCurrency };
+    let rpc_request: RpcRequest<listCurrencyRatesRequest> =
+        RpcRequest::new("AccountAPING/v1.0/listCurrencyRates".to_owned(), req);
+    let resp: RpcResponse<Vec<CurrencyRate>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
+}
@@ -0,0 +1,70 @@
+// Programmatic generation of the non-interactive login identity Betfair's
+// cert-login flow requires, replacing the manual dance described at the top
+// of `main.rs` (`openssl x509 -x509toreq ...`) and the hand-made `.pfx` file
+// it produces. Uses the `openssl` crate directly, the same way `acmec`
+// builds its own CSRs/certificates in-process rather than shelling out.
+
+use crate::AnyError;
+use openssl::asn1::Asn1Time;
+use openssl::hash::MessageDigest;
+use openssl::pkcs12::Pkcs12;
+use openssl::pkey::PKey;
+use openssl::rsa::Rsa;
+use openssl::x509::extension::SubjectAlternativeName;
+use openssl::x509::{X509NameBuilder, X509};
+
+const RSA_BITS: u32 = 2048;
+const VALIDITY_DAYS: u32 = 365 * 5;
+
+/// The freshly-generated identity: a PKCS#12 bundle ready for
+/// `reqwest::Identity::from_pkcs12_der`, plus the PEM certificate the user
+/// must upload to their Betfair account under API access.
+pub struct GeneratedIdentity {
+    pub pkcs12_der: Vec<u8>,
+    pub certificate_pem: Vec<u8>,
+}
+
+impl From<openssl::error::ErrorStack> for AnyError {
+    fn from(e: openssl::error::ErrorStack) -> Self {
+        AnyError::Openssl(e)
+    }
+}
+
+/// Generates a 2048-bit RSA key and a self-signed certificate for
+/// `common_name`, then packages both into a password-less PKCS#12 bundle.
+///
+/// Betfair's non-interactive login only checks that the certificate
+/// presented during the TLS handshake matches the one uploaded to the
+/// account, so a self-signed certificate (rather than one from a CSR signed
+/// by a real CA) is sufficient here.
+pub fn generate_identity(common_name: &str) -> Result<GeneratedIdentity, AnyError> {
+    let rsa = Rsa::generate(RSA_BITS)?;
+    let pkey = PKey::from_rsa(rsa)?;
+
+    let mut name_builder = X509NameBuilder::new()?;
+    name_builder.append_entry_by_text("CN", common_name)?;
+    let name = name_builder.build();
+
+    let mut builder = X509::builder()?;
+    builder.set_version(2)?;
+    builder.set_subject_name(&name)?;
+    builder.set_issuer_name(&name)?;
+    builder.set_pubkey(&pkey)?;
+    builder.set_not_before(Asn1Time::days_from_now(0)?.as_ref())?;
+    builder.set_not_after(Asn1Time::days_from_now(VALIDITY_DAYS)?.as_ref())?;
+
+    let san = SubjectAlternativeName::new()
+        .dns(common_name)
+        .build(&builder.x509v3_context(None, None))?;
+    builder.append_extension(san)?;
+
+    builder.sign(&pkey, MessageDigest::sha256())?;
+    let certificate = builder.build();
+
+    let pkcs12 = Pkcs12::builder().build("", common_name, &pkey, &certificate)?;
+
+    Ok(GeneratedIdentity {
+        pkcs12_der: pkcs12.to_der()?,
+        certificate_pem: certificate.to_pem()?,
+    })
+}
@@ -18,11 +18,63 @@ use crate::generated_exceptions::errorCode;
 use crate::json_rpc::{RpcRequest, RpcResponse};
 use crate::result::{Error, Result};
 use reqwest::{Client, Identity};
+use secrecy::{ExposeSecret, SecretString};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::sync::{mpsc, Arc, RwLock};
+use std::sync::{mpsc, Arc, Mutex, RwLock};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+/// Betfair's documented safe request rate across *all* connections sharing
+/// an application key, used as [`BFClientBuilder`]'s default.
+const DEFAULT_RATE_LIMIT_PER_SEC: f64 = 4.0;
+
+/// A token-bucket rate limiter shared by every request a [`BFClient`] makes,
+/// including keepalive and login, so a bot wrapping the client in an `Arc`
+/// across many threads can never collectively exceed Betfair's safe request
+/// rate (and its heavily-weighted transaction costs).
+struct Bucket {
+    tokens: f64,
+    capacity: f64,
+    refill_per_sec: f64,
+    last: Instant,
+}
+
+impl Bucket {
+    fn new(capacity: f64, refill_per_sec: f64) -> Self {
+        Bucket {
+            tokens: capacity,
+            capacity,
+            refill_per_sec,
+            last: Instant::now(),
+        }
+    }
+
+    /// Blocks the calling thread, sleeping and retrying as needed, until
+    /// `weight` tokens are available, then consumes them. Expensive calls
+    /// (e.g. `placeOrders`) can pass a `weight` above `1.0` to consume more
+    /// of the budget than a cheap one.
+    fn acquire(bucket: &Mutex<Bucket>, weight: f64) {
+        loop {
+            let wait = {
+                let mut bucket = bucket.lock().expect("rate limiter lock poisoned");
+                let now = Instant::now();
+                let elapsed = now.duration_since(bucket.last).as_secs_f64();
+                bucket.last = now;
+                bucket.tokens =
+                    (bucket.tokens + elapsed * bucket.refill_per_sec).min(bucket.capacity);
+
+                if bucket.tokens >= weight {
+                    bucket.tokens -= weight;
+                    return;
+                }
+
+                (weight - bucket.tokens) / bucket.refill_per_sec
+            };
+            thread::sleep(Duration::from_secs_f64(wait));
+        }
+    }
+}
 
 #[derive(Debug, Serialize)]
 struct LoginRequestForm {
@@ -37,43 +89,134 @@ struct LoginResponse {
     loginStatus: String, // TODO enum this
 }
 
+/// The client certificate used to authenticate with Betfair's identity SSO,
+/// in whichever format it was supplied to `BFCredentials`. Kept as the raw
+/// bytes (plus format and pkcs12 password, where relevant) rather than a
+/// built `reqwest::Identity`, since `Identity` isn't `Clone` and
+/// `BFCredentials` needs to be.
+#[derive(Clone)]
+enum ClientIdentity {
+    /// A password-less-by-default PKCS#12 bundle, as produced by `openssl
+    /// pkcs12 -export`. See [`BFCredentials::from_pkcs12`].
+    Pkcs12 {
+        der: Vec<u8>,
+        password: SecretString,
+    },
+    /// A PEM-encoded certificate and private key pair, as issued directly by
+    /// Betfair (`client-2048.crt`/`client-2048.key`). See
+    /// [`BFCredentials::from_pem`].
+    Pem { cert: Vec<u8>, key: Vec<u8> },
+}
+
 /// A container for the essential credentials required for the Betfair APING.
+///
+/// `password` is wrapped in a `SecretString`, which zeroizes its contents on
+/// drop and redacts them from `Debug` output, so it doesn't linger in memory
+/// or leak through logs any longer than it has to.
+#[derive(Clone)]
 pub struct BFCredentials {
     username: String,
-    password: String,
-    pfx: Vec<u8>,
+    password: SecretString,
+    client_identity: ClientIdentity,
     app_key: String,
 }
 
 impl BFCredentials {
+    /// Creates credentials from a password-less PKCS#12 bundle, as produced
+    /// by `openssl pkcs12 -export` without a `-passout`. Equivalent to
+    /// `from_pkcs12` with an empty `pfx_password`; kept for callers already
+    /// depending on this signature.
     pub fn new(
         username: String,
         password: String,
         pfx_path: String,
         app_key: String,
     ) -> Result<Self> {
-        let pfx = std::fs::read(pfx_path)?;
+        BFCredentials::from_pkcs12(username, password, pfx_path, String::new(), app_key)
+    }
+
+    /// Creates credentials from a PKCS#12 bundle protected by `pfx_password`
+    /// (pass an empty string if it was exported without one, e.g. via
+    /// `openssl pkcs12 -export` with no `-passout`).
+    pub fn from_pkcs12(
+        username: String,
+        password: String,
+        pfx_path: String,
+        pfx_password: String,
+        app_key: String,
+    ) -> Result<Self> {
+        let der = std::fs::read(pfx_path)?;
+        Ok(BFCredentials {
+            username,
+            password: SecretString::new(password),
+            client_identity: ClientIdentity::Pkcs12 {
+                der,
+                password: SecretString::new(pfx_password),
+            },
+            app_key,
+        })
+    }
+
+    /// Creates credentials directly from the PEM-encoded private key and
+    /// certificate Betfair issues (`client-2048.key`/`client-2048.crt`),
+    /// without requiring the manual `openssl pkcs12 -export` conversion
+    /// `from_pkcs12`/`new` need.
+    pub fn from_pem(
+        username: String,
+        password: String,
+        key_path: String,
+        crt_path: String,
+        app_key: String,
+    ) -> Result<Self> {
+        let key = std::fs::read(key_path)?;
+        let cert = std::fs::read(crt_path)?;
         Ok(BFCredentials {
             username,
-            password,
-            pfx,
+            password: SecretString::new(password),
+            client_identity: ClientIdentity::Pem { cert, key },
             app_key,
         })
     }
+
     fn as_login_request_form(&self) -> LoginRequestForm {
         LoginRequestForm {
             username: self.username.clone(),
-            password: self.password.clone(),
+            password: self.password.expose_secret().clone(),
         }
     }
-    fn pfx(&self) -> &Vec<u8> {
-        &self.pfx
+
+    /// Builds the `reqwest::Identity` to present for this client's stored
+    /// certificate, in whichever format it was supplied.
+    fn identity(&self) -> Result<Identity> {
+        Ok(match &self.client_identity {
+            ClientIdentity::Pkcs12 { der, password } => {
+                Identity::from_pkcs12_der(der.as_slice(), password.expose_secret())?
+            }
+            ClientIdentity::Pem { cert, key } => {
+                // `from_pkcs8_pem` is rustls-tls-only; `login_internal`
+                // already requires `from_pkcs12_der` (native-tls-only), so
+                // build the identity the native-tls way here too: a single
+                // PEM buffer containing both the certificate and key.
+                let mut pem = cert.clone();
+                pem.extend_from_slice(key.as_slice());
+                Identity::from_pem(pem.as_slice())?
+            }
+        })
     }
+
     fn app_key(&self) -> &String {
         &self.app_key
     }
 }
 
+/// `SecretString` deliberately doesn't implement `PartialEq` (to rule out
+/// accidental, non-constant-time secret comparisons elsewhere), so the
+/// "did another thread/task already refresh the token" checks in
+/// `req_weighted` need an explicit, scoped `expose_secret()` instead of `==`.
+fn tokens_equal(a: &Option<SecretString>, b: &Option<SecretString>) -> bool {
+    a.as_ref().map(ExposeSecret::expose_secret) == b.as_ref().map(ExposeSecret::expose_secret)
+}
+
 /// A thread-safe client with automatic login implementing all methods of the
 /// Betfair SportsAPING.
 ///
@@ -82,9 +225,10 @@ impl BFCredentials {
 pub struct BFClient {
     client: reqwest::Client,
     destructor: mpsc::SyncSender<()>,
-    session_token: Arc<RwLock<Option<String>>>,
+    session_token: Arc<RwLock<Option<SecretString>>>,
     creds: BFCredentials,
     proxy_uri: Option<String>,
+    rate_limiter: Arc<Mutex<Bucket>>,
 }
 
 impl Drop for BFClient {
@@ -97,11 +241,45 @@ impl Drop for BFClient {
 }
 
 impl BFClient {
-    pub fn new(
-        creds: BFCredentials,
-        proxy_uri: Option<String>,
-    ) -> Result<Self> {
-        let client: reqwest::Client = match &proxy_uri {
+    /// Creates a client with the default rate limit
+    /// (`DEFAULT_RATE_LIMIT_PER_SEC`, 4/sec). See
+    /// [`BFClient::builder`](Self::builder) to configure it.
+    pub fn new(creds: BFCredentials, proxy_uri: Option<String>) -> Result<Self> {
+        BFClient::builder(creds, proxy_uri).build()
+    }
+
+    /// Starts building a client, so the rate limit can be configured before
+    /// it is constructed.
+    pub fn builder(creds: BFCredentials, proxy_uri: Option<String>) -> BFClientBuilder {
+        BFClientBuilder {
+            creds,
+            proxy_uri,
+            rate_limit_per_sec: DEFAULT_RATE_LIMIT_PER_SEC,
+        }
+    }
+}
+
+/// Builder for [`BFClient`]. Currently only configures the shared rate
+/// limit, but keeps `new` from having to grow a parameter for every future
+/// connection option.
+pub struct BFClientBuilder {
+    creds: BFCredentials,
+    proxy_uri: Option<String>,
+    rate_limit_per_sec: f64,
+}
+
+impl BFClientBuilder {
+    /// Caps the combined request rate of every call the built client makes
+    /// (`req`, keepalive, login) at `per_sec` tokens/sec. Defaults to
+    /// `DEFAULT_RATE_LIMIT_PER_SEC` (4/sec), Betfair's documented safe
+    /// limit.
+    pub fn rate_limit_per_sec(mut self, per_sec: f64) -> Self {
+        self.rate_limit_per_sec = per_sec;
+        self
+    }
+
+    pub fn build(self) -> Result<BFClient> {
+        let client: reqwest::Client = match &self.proxy_uri {
             Some(uri) => {
                 let proxy = reqwest::Proxy::all(uri)?;
                 Client::builder().proxy(proxy).build()?
@@ -110,13 +288,19 @@ impl BFClient {
         };
 
         let session_token = Arc::new(RwLock::new(None));
+        let rate_limiter = Arc::new(Mutex::new(Bucket::new(
+            self.rate_limit_per_sec,
+            self.rate_limit_per_sec,
+        )));
 
         let destructor = {
             let session_token = session_token.clone();
-            let proxy_uri = proxy_uri.clone();
+            let creds = self.creds.clone();
+            let proxy_uri = self.proxy_uri.clone();
+            let rate_limiter = rate_limiter.clone();
             let (tx, rx) = mpsc::sync_channel(0); // rendezvous channel
             thread::spawn(|| {
-                Self::keepalive_thread(session_token, proxy_uri, rx)
+                BFClient::keepalive_thread(session_token, creds, proxy_uri, rate_limiter, rx)
             });
             tx
         };
@@ -125,28 +309,29 @@ impl BFClient {
             client,
             destructor,
             session_token,
-            creds,
-            proxy_uri,
+            creds: self.creds,
+            proxy_uri: self.proxy_uri,
+            rate_limiter,
         })
     }
+}
 
+impl BFClient {
     /// This function is run once per BFClient as a thread. It ensures that the
     /// correct keepalive requests are made to the Betfair API such that the
-    /// token does not expire.
-    ///
-    /// Note that it does not automatically re-login on expiry; for that to
-    /// occur, a request must explicitly be made.
-    ///
-    /// In the future this could be implemented, which would reduce the latency
-    /// of the first call after a (very) long spell of nothing, the so-called
-    /// 'cold start problem'.
+    /// token does not expire, and proactively re-logs-in in the background
+    /// when it detects that the token has expired or is missing, so that the
+    /// next `req` doesn't pay full login latency (the so-called 'cold start
+    /// problem').
     fn keepalive_thread(
-        session_token: Arc<RwLock<Option<String>>>,
+        session_token: Arc<RwLock<Option<SecretString>>>,
+        creds: BFCredentials,
         proxy_uri: Option<String>,
+        rate_limiter: Arc<Mutex<Bucket>>,
         rx: mpsc::Receiver<()>,
     ) {
         trace!("keepalive: thread spawned");
-        let mut expired_token: Option<String> = None;
+        let mut expired_token: Option<SecretString> = None;
         loop {
             match rx.recv_timeout(Duration::from_millis(60000)) {
                 Ok(_) => {
@@ -154,33 +339,55 @@ impl BFClient {
                     break;
                 }
                 Err(_) => {
-                    let maybe_token: Option<String> = session_token
+                    let maybe_token: Option<SecretString> = session_token
                         .read()
                         .expect("keepalive: could not lock session token")
                         .clone();
 
-                    if maybe_token.is_some() && maybe_token == expired_token {
-                        // TODO: login instead
-                        warn!("keepalive: skipping, as token is expired");
-                    }
-
-                    match maybe_token {
-                        None => {
-                            debug!("keepalive: skipping, as no token");
-                        }
-                        Some(token) => {
-                            debug!("keepalive: attempting");
-                            match keepalive(&token, &proxy_uri) {
-                                Ok(()) => {
-                                    debug!("keepalive: successful");
+                    if maybe_token.is_none() || tokens_equal(&maybe_token, &expired_token) {
+                        debug!("keepalive: logging in to refresh session token");
+                        let mut attempt: u32 = 0;
+                        let logged_in = loop {
+                            match login_internal(&creds, &proxy_uri, &rate_limiter) {
+                                Ok(token) => {
+                                    info!("keepalive: login successful");
+                                    *session_token
+                                        .write()
+                                        .expect("keepalive: could not lock session token") =
+                                        Some(token);
+                                    expired_token = None;
+                                    break true;
                                 }
                                 Err(e) => {
-                                    info!("keepalive failed: {:?}", e);
-                                    // TODO: login instead
-                                    expired_token = Some(token);
+                                    warn!("keepalive: login failed: {:?}", e);
+                                    let delay = login_backoff(attempt);
+                                    attempt = attempt.saturating_add(1);
+                                    match rx.recv_timeout(delay) {
+                                        Ok(_) => break false,
+                                        Err(_) => continue,
+                                    }
                                 }
-                            };
+                            }
+                        };
+                        if !logged_in {
+                            warn!("keepalive: destructor signal caught, exiting");
+                            break;
                         }
+                        continue;
+                    }
+
+                    if let Some(token) = maybe_token {
+                        debug!("keepalive: attempting");
+                        Bucket::acquire(&rate_limiter, 1.0);
+                        match keepalive(&token, &proxy_uri) {
+                            Ok(()) => {
+                                debug!("keepalive: successful");
+                            }
+                            Err(e) => {
+                                info!("keepalive failed: {:?}", e);
+                                expired_token = Some(token);
+                            }
+                        };
                     }
                 }
             };
@@ -189,16 +396,18 @@ impl BFClient {
 
     fn req_internal<T1: Serialize, T2: DeserializeOwned>(
         &self,
-        maybe_token: &Option<String>,
+        maybe_token: &Option<SecretString>,
         rpc_request: &RpcRequest<T1>,
+        weight: f64,
     ) -> Result<T2> {
         let token = match maybe_token {
             Some(x) => x,
             None => return Err(Error::SessionTokenNotPresent),
         };
 
-        const JSONRPC_URI: &str =
-            "https://api.betfair.com/exchange/betting/json-rpc/v1";
+        const JSONRPC_URI: &str = "https://api.betfair.com/exchange/betting/json-rpc/v1";
+
+        Bucket::acquire(&self.rate_limiter, weight);
 
         trace!("Performing a query to the JSON-RPC api");
 
@@ -208,7 +417,7 @@ impl BFClient {
                 .client
                 .post(JSONRPC_URI)
                 .header("X-Application", self.creds.app_key())
-                .header("X-Authentication", token)
+                .header("X-Authentication", token.expose_secret())
                 .json(&rpc_request)
                 .send();
 
@@ -218,9 +427,8 @@ impl BFClient {
                     match e
                         .get_ref()
                         .and_then(|f| f.downcast_ref::<http::Error>())
-                        .and_then(|g| {
-                            Some(g.is::<http::header::InvalidHeaderValue>())
-                        }) {
+                        .and_then(|g| Some(g.is::<http::header::InvalidHeaderValue>()))
+                    {
                         Some(true) => {
                             // This error occurs if you pass a random
                             //   string in the authentication header.
@@ -245,12 +453,13 @@ impl BFClient {
             }
         };
 
-        match rpc_response.into_inner() {
+        match rpc_response.into_inner().map_err(Error::from) {
             Ok(x) => Ok(x),
-            Err(Error::APINGException(code)) => match code {
-                errorCode::INVALID_SESSION_INFORMATION
-                | errorCode::NO_SESSION => Err(Error::SessionTokenInvalid),
-                e => {
+            Err(Error::APINGException(e)) => match e.error_code {
+                errorCode::INVALID_SESSION_INFORMATION | errorCode::NO_SESSION => {
+                    Err(Error::SessionTokenInvalid)
+                }
+                _ => {
                     error!("req_internal: API error {:?}", e);
                     Err(Error::APINGException(e))
                 }
@@ -265,9 +474,23 @@ impl BFClient {
         }
     }
 
+    /// Performs `req` at the default weight of `1.0` token. See
+    /// [`req_weighted`](Self::req_weighted) for calls that should consume
+    /// more of the shared rate limit budget.
     pub(super) fn req<T1: Serialize, T2: DeserializeOwned>(
         &self,
         req: RpcRequest<T1>,
+    ) -> Result<T2> {
+        self.req_weighted(req, 1.0)
+    }
+
+    /// As [`req`](Self::req), but consuming `weight` tokens from the shared
+    /// rate limiter instead of `1.0`, for calls that are more expensive to
+    /// Betfair (e.g. `placeOrders`, `listMarketBook`).
+    pub(super) fn req_weighted<T1: Serialize, T2: DeserializeOwned>(
+        &self,
+        req: RpcRequest<T1>,
+        weight: f64,
     ) -> Result<T2> {
         // Initially acquire the token via a read lock
 
@@ -279,36 +502,39 @@ impl BFClient {
 
         loop {
             debug!("req: attempting request");
-            match self.req_internal(&token, &req) {
+            match self.req_internal(&token, &req, weight) {
                 Ok(resp) => {
                     debug!("req: request successful");
                     break Ok(resp);
                 }
-                Err(Error::SessionTokenNotPresent)
-                | Err(Error::SessionTokenInvalid) => {
+                Err(Error::SessionTokenNotPresent) | Err(Error::SessionTokenInvalid) => {
                     info!("req: login required");
                     trace!("req: taking token write lock");
                     let mut token_lock = self.session_token.write().unwrap();
 
-                    if token != *token_lock {
+                    if !tokens_equal(&token, &token_lock) {
                         // Another thread has already performed the login.
                         token = token_lock.clone();
                         continue;
                     }
 
-                    token = loop {
-                        debug!("login: sending request");
-                        match self.login() {
-                            Ok(token) => {
-                                info!("login: success");
-                                break Some(token);
-                            }
-                            Err(e) => {
-                                warn!("login: failed {:?}", e);
+                    token = {
+                        let mut attempt: u32 = 0;
+                        loop {
+                            debug!("login: sending request");
+                            match self.login() {
+                                Ok(token) => {
+                                    info!("login: success");
+                                    break Some(token);
+                                }
+                                Err(e) => {
+                                    warn!("login: failed {:?}", e);
 
-                                // TODO: exponential backoff
-                                debug!("login: sleeping for 5000ms");
-                                thread::sleep(Duration::from_millis(5000));
+                                    let delay = login_backoff(attempt);
+                                    attempt = attempt.saturating_add(1);
+                                    debug!("login: sleeping for {:?}", delay);
+                                    thread::sleep(delay);
+                                }
                             }
                         }
                     };
@@ -325,45 +551,71 @@ impl BFClient {
         }
     }
 
-    fn login(&self) -> Result<String> {
-        const CERTLOGIN_URI: &str =
-            "https://identitysso-cert.betfair.com/api/certlogin";
+    fn login(&self) -> Result<SecretString> {
+        login_internal(&self.creds, &self.proxy_uri, &self.rate_limiter)
+    }
+}
 
-        let ident =
-            Identity::from_pkcs12_der(self.creds.pfx().as_slice(), "")?;
+/// Shared by [`BFClient::login`](BFClient::login) and
+/// [`BFClient::keepalive_thread`](BFClient::keepalive_thread) (which has no
+/// `&self` to call the method on), since both need to perform exactly the
+/// same certificate login.
+fn login_internal(
+    creds: &BFCredentials,
+    proxy_uri: &Option<String>,
+    rate_limiter: &Arc<Mutex<Bucket>>,
+) -> Result<SecretString> {
+    const CERTLOGIN_URI: &str = "https://identitysso-cert.betfair.com/api/certlogin";
 
-        let client: reqwest::Client = match &(self.proxy_uri) {
-            Some(uri) => {
-                let proxy = reqwest::Proxy::all(uri)?;
-                Client::builder().identity(ident).proxy(proxy).build()?
-            }
-            None => Client::builder().identity(ident).build()?,
-        };
+    Bucket::acquire(rate_limiter, 1.0);
 
-        let login_request_form = self.creds.as_login_request_form();
+    let ident = creds.identity()?;
 
-        let login_response: LoginResponse = client
-            .post(CERTLOGIN_URI)
-            .header(
-                "X-Application",
-                format!("schroedinger_{}", rand::random::<u128>()),
-            )
-            .form(&login_request_form)
-            .send()?
-            .json()?;
-        match login_response.sessionToken {
-            Some(token) => Ok(token),
-            None => Err(Error::BFLoginFailure(format!(
-                "loginStatus: {}",
-                login_response.loginStatus
-            ))),
+    let client: reqwest::Client = match proxy_uri {
+        Some(uri) => {
+            let proxy = reqwest::Proxy::all(uri)?;
+            Client::builder().identity(ident).proxy(proxy).build()?
         }
+        None => Client::builder().identity(ident).build()?,
+    };
+
+    let login_request_form = creds.as_login_request_form();
+
+    let login_response: LoginResponse = client
+        .post(CERTLOGIN_URI)
+        .header(
+            "X-Application",
+            format!("schroedinger_{}", rand::random::<u128>()),
+        )
+        .form(&login_request_form)
+        .send()?
+        .json()?;
+    match login_response.sessionToken {
+        Some(token) => Ok(SecretString::new(token)),
+        None => Err(Error::BFLoginFailure(format!(
+            "loginStatus: {}",
+            login_response.loginStatus
+        ))),
     }
 }
 
-fn keepalive(token: &String, proxy_uri: &Option<String>) -> Result<()> {
-    const KEEPALIVE_URI: &str =
-        "https://identitysso.betfair.com/api/keepAlive";
+/// The delay before the `attempt`'th (0-indexed) retry of a failed login:
+/// `500ms * 2^attempt`, capped at 60s, with up to 20% jitter so that many
+/// clients failing to log in at once don't retry in lockstep against
+/// Betfair's identity SSO.
+fn login_backoff(attempt: u32) -> Duration {
+    const BASE_MS: u64 = 500;
+    const CAP_MS: u64 = 60_000;
+    let exp_ms = 1u64
+        .checked_shl(attempt)
+        .map_or(CAP_MS, |factor| BASE_MS.saturating_mul(factor))
+        .min(CAP_MS);
+    let jitter_ms = (exp_ms as f64 * 0.2 * rand::random::<f64>()) as u64;
+    Duration::from_millis(exp_ms + jitter_ms)
+}
+
+fn keepalive(token: &SecretString, proxy_uri: &Option<String>) -> Result<()> {
+    const KEEPALIVE_URI: &str = "https://identitysso.betfair.com/api/keepAlive";
 
     let client: Client = match proxy_uri {
         Some(uri) => {
@@ -380,7 +632,7 @@ fn keepalive(token: &String, proxy_uri: &Option<String>) -> Result<()> {
             "X-Application",
             format!("schroedinger_{}", rand::random::<u128>()),
         )
-        .header("X-Authentication", token)
+        .header("X-Authentication", token.expose_secret())
         .send()?
         .json()?;
 
@@ -418,3 +670,448 @@ struct KeepAliveResponse {
     status: KeepAliveStatus,
     error: Option<KeepAliveError>,
 }
+
+/// Async counterpart to [`BFClient`], built on `reqwest`'s async `Client`
+/// so a bot driving hundreds of concurrent markets doesn't need a thread per
+/// in-flight request. Only compiled in when the `async` feature is enabled,
+/// so blocking-only users aren't forced to pull in a runtime. The sync
+/// `BFClient` above is left untouched rather than rebuilt atop this, so
+/// existing blocking callers see no behavior change.
+#[cfg(feature = "async")]
+pub mod nonblocking {
+    use super::{
+        tokens_equal, BFCredentials, KeepAliveResponse, KeepAliveStatus, LoginResponse,
+        DEFAULT_RATE_LIMIT_PER_SEC,
+    };
+    use crate::generated_exceptions::errorCode;
+    use crate::json_rpc::{RpcRequest, RpcResponse};
+    use crate::result::{Error, Result};
+    use reqwest::Client;
+    use secrecy::{ExposeSecret, SecretString};
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use tokio::sync::{Mutex, RwLock};
+    use tokio::task::JoinHandle;
+
+    /// Async counterpart to the blocking [`Bucket`](super::Bucket), sharing
+    /// the same token-bucket algorithm but sleeping via `tokio::time::sleep`
+    /// instead of blocking the calling thread.
+    struct AsyncBucket {
+        tokens: f64,
+        capacity: f64,
+        refill_per_sec: f64,
+        last: Instant,
+    }
+
+    impl AsyncBucket {
+        fn new(capacity: f64, refill_per_sec: f64) -> Self {
+            AsyncBucket {
+                tokens: capacity,
+                capacity,
+                refill_per_sec,
+                last: Instant::now(),
+            }
+        }
+
+        async fn acquire(bucket: &Mutex<AsyncBucket>, weight: f64) {
+            loop {
+                let wait = {
+                    let mut bucket = bucket.lock().await;
+                    let now = Instant::now();
+                    let elapsed = now.duration_since(bucket.last).as_secs_f64();
+                    bucket.last = now;
+                    bucket.tokens =
+                        (bucket.tokens + elapsed * bucket.refill_per_sec).min(bucket.capacity);
+
+                    if bucket.tokens >= weight {
+                        bucket.tokens -= weight;
+                        return;
+                    }
+
+                    (weight - bucket.tokens) / bucket.refill_per_sec
+                };
+                tokio::time::sleep(Duration::from_secs_f64(wait)).await;
+            }
+        }
+    }
+
+    /// Async counterpart to [`BFClient`](super::BFClient). The session
+    /// token is guarded by a `tokio::sync::RwLock` so the login retry loop
+    /// never blocks the executor, and keepalive runs as a spawned task
+    /// driven by `tokio::time::interval` instead of a dedicated OS thread.
+    pub struct BFClientAsync {
+        client: reqwest::Client,
+        session_token: Arc<RwLock<Option<SecretString>>>,
+        creds: BFCredentials,
+        proxy_uri: Option<String>,
+        rate_limiter: Arc<Mutex<AsyncBucket>>,
+        keepalive_handle: JoinHandle<()>,
+    }
+
+    impl Drop for BFClientAsync {
+        fn drop(&mut self) {
+            self.keepalive_handle.abort();
+        }
+    }
+
+    impl BFClientAsync {
+        /// Creates a client with the default rate limit
+        /// (`DEFAULT_RATE_LIMIT_PER_SEC`, 4/sec). Must be called from
+        /// within a `tokio` runtime, since it spawns the keepalive task.
+        pub fn new(creds: BFCredentials, proxy_uri: Option<String>) -> Result<Self> {
+            BFClientAsync::builder(creds, proxy_uri).build()
+        }
+
+        /// Starts building a client, so the rate limit can be configured
+        /// before it is constructed.
+        pub fn builder(creds: BFCredentials, proxy_uri: Option<String>) -> BFClientAsyncBuilder {
+            BFClientAsyncBuilder {
+                creds,
+                proxy_uri,
+                rate_limit_per_sec: DEFAULT_RATE_LIMIT_PER_SEC,
+            }
+        }
+
+        /// As [`BFClient::keepalive_thread`](super::BFClient::keepalive_thread),
+        /// but proactively re-logging-in is just another `.await` in the loop
+        /// rather than a blocking retry, since this is already running as a
+        /// spawned task.
+        async fn keepalive_loop(
+            session_token: Arc<RwLock<Option<SecretString>>>,
+            creds: BFCredentials,
+            proxy_uri: Option<String>,
+            rate_limiter: Arc<Mutex<AsyncBucket>>,
+        ) {
+            trace!("keepalive: task spawned");
+            let mut expired_token: Option<SecretString> = None;
+            let mut interval = tokio::time::interval(Duration::from_millis(60000));
+            loop {
+                interval.tick().await;
+
+                let maybe_token: Option<SecretString> = session_token.read().await.clone();
+
+                if maybe_token.is_none() || tokens_equal(&maybe_token, &expired_token) {
+                    debug!("keepalive: logging in to refresh session token");
+                    let mut attempt: u32 = 0;
+                    loop {
+                        match login_internal(&creds, &proxy_uri, &rate_limiter).await {
+                            Ok(token) => {
+                                info!("keepalive: login successful");
+                                *session_token.write().await = Some(token);
+                                expired_token = None;
+                                break;
+                            }
+                            Err(e) => {
+                                warn!("keepalive: login failed: {:?}", e);
+                                let delay = super::login_backoff(attempt);
+                                attempt = attempt.saturating_add(1);
+                                tokio::time::sleep(delay).await;
+                            }
+                        }
+                    }
+                    continue;
+                }
+
+                if let Some(token) = maybe_token {
+                    debug!("keepalive: attempting");
+                    AsyncBucket::acquire(&rate_limiter, 1.0).await;
+                    match keepalive(&token, &proxy_uri).await {
+                        Ok(()) => {
+                            debug!("keepalive: successful");
+                        }
+                        Err(e) => {
+                            info!("keepalive failed: {:?}", e);
+                            expired_token = Some(token);
+                        }
+                    }
+                }
+            }
+        }
+
+        async fn req_internal<T1: Serialize, T2: DeserializeOwned>(
+            &self,
+            maybe_token: &Option<SecretString>,
+            rpc_request: &RpcRequest<T1>,
+            weight: f64,
+        ) -> Result<T2> {
+            let token = match maybe_token {
+                Some(x) => x,
+                None => return Err(Error::SessionTokenNotPresent),
+            };
+
+            const JSONRPC_URI: &str = "https://api.betfair.com/exchange/betting/json-rpc/v1";
+
+            AsyncBucket::acquire(&self.rate_limiter, weight).await;
+
+            trace!("Performing a query to the JSON-RPC api");
+
+            let maybe_http_response = self
+                .client
+                .post(JSONRPC_URI)
+                .header("X-Application", self.creds.app_key())
+                .header("X-Authentication", token.expose_secret())
+                .json(&rpc_request)
+                .send()
+                .await;
+
+            let mut http_response: reqwest::Response = match maybe_http_response {
+                Ok(x) => x,
+                Err(e) => {
+                    match e
+                        .get_ref()
+                        .and_then(|f| f.downcast_ref::<http::Error>())
+                        .and_then(|g| Some(g.is::<http::header::InvalidHeaderValue>()))
+                    {
+                        Some(true) => {
+                            debug!("req_internal: InvalidHeaderValue");
+                            return Err(Error::SessionTokenInvalid);
+                        }
+                        _ => {
+                            error!("req_internal: request error {}", e);
+                            return Err(Error::Reqwest(e));
+                        }
+                    }
+                }
+            };
+
+            let rpc_response: RpcResponse<T2> = match http_response.json().await {
+                Ok(x) => x,
+                Err(e) => {
+                    error!("req_internal: deserialization error {}", e);
+                    return Err(Error::Reqwest(e));
+                }
+            };
+
+            match rpc_response.into_inner().map_err(Error::from) {
+                Ok(x) => Ok(x),
+                Err(Error::APINGException(e)) => match e.error_code {
+                    errorCode::INVALID_SESSION_INFORMATION | errorCode::NO_SESSION => {
+                        Err(Error::SessionTokenInvalid)
+                    }
+                    _ => {
+                        error!("req_internal: API error {:?}", e);
+                        Err(Error::APINGException(e))
+                    }
+                },
+                Err(Error::JSONRPCError) => {
+                    error!("req_internal: no result or error?");
+                    Err(Error::JSONRPCError)
+                }
+                Err(_) => {
+                    unreachable!();
+                }
+            }
+        }
+
+        /// Performs `req` at the default weight of `1.0` token. See
+        /// [`req_weighted`](Self::req_weighted) for calls that should
+        /// consume more of the shared rate limit budget.
+        pub(super) async fn req<T1: Serialize, T2: DeserializeOwned>(
+            &self,
+            req: RpcRequest<T1>,
+        ) -> Result<T2> {
+            self.req_weighted(req, 1.0).await
+        }
+
+        /// As [`req`](Self::req), but consuming `weight` tokens from the
+        /// shared rate limiter instead of `1.0`.
+        pub(super) async fn req_weighted<T1: Serialize, T2: DeserializeOwned>(
+            &self,
+            req: RpcRequest<T1>,
+            weight: f64,
+        ) -> Result<T2> {
+            trace!("req: taking token read lock");
+            let mut token = self.session_token.read().await.clone();
+            trace!("req: dropped token read lock");
+
+            loop {
+                debug!("req: attempting request");
+                match self.req_internal(&token, &req, weight).await {
+                    Ok(resp) => {
+                        debug!("req: request successful");
+                        break Ok(resp);
+                    }
+                    Err(Error::SessionTokenNotPresent) | Err(Error::SessionTokenInvalid) => {
+                        info!("req: login required");
+                        trace!("req: taking token write lock");
+                        let mut token_lock = self.session_token.write().await;
+
+                        if !tokens_equal(&token, &token_lock) {
+                            // Another task has already performed the login.
+                            token = token_lock.clone();
+                            continue;
+                        }
+
+                        token = {
+                            let mut attempt: u32 = 0;
+                            loop {
+                                debug!("login: sending request");
+                                match self.login().await {
+                                    Ok(token) => {
+                                        info!("login: success");
+                                        break Some(token);
+                                    }
+                                    Err(e) => {
+                                        warn!("login: failed {:?}", e);
+
+                                        let delay = super::login_backoff(attempt);
+                                        attempt = attempt.saturating_add(1);
+                                        debug!("login: sleeping for {:?}", delay);
+                                        tokio::time::sleep(delay).await;
+                                    }
+                                }
+                            }
+                        };
+
+                        *token_lock = token.clone();
+                        drop(token_lock); // explicit drop for logging purposes
+                        trace!("req: dropped token write lock");
+                    }
+                    Err(e) => {
+                        error!("req: unhandled error {:?}", e);
+                        break Err(e);
+                    }
+                }
+            }
+        }
+
+        async fn login(&self) -> Result<SecretString> {
+            login_internal(&self.creds, &self.proxy_uri, &self.rate_limiter).await
+        }
+    }
+
+    /// Async counterpart to [`super::login_internal`]; shared by
+    /// [`BFClientAsync::login`](BFClientAsync::login) and
+    /// [`BFClientAsync::keepalive_loop`](BFClientAsync::keepalive_loop).
+    async fn login_internal(
+        creds: &BFCredentials,
+        proxy_uri: &Option<String>,
+        rate_limiter: &Arc<Mutex<AsyncBucket>>,
+    ) -> Result<SecretString> {
+        const CERTLOGIN_URI: &str = "https://identitysso-cert.betfair.com/api/certlogin";
+
+        AsyncBucket::acquire(rate_limiter, 1.0).await;
+
+        let ident = creds.identity()?;
+
+        let client: reqwest::Client = match proxy_uri {
+            Some(uri) => {
+                let proxy = reqwest::Proxy::all(uri)?;
+                Client::builder().identity(ident).proxy(proxy).build()?
+            }
+            None => Client::builder().identity(ident).build()?,
+        };
+
+        let login_request_form = creds.as_login_request_form();
+
+        let login_response: LoginResponse = client
+            .post(CERTLOGIN_URI)
+            .header(
+                "X-Application",
+                format!("schroedinger_{}", rand::random::<u128>()),
+            )
+            .form(&login_request_form)
+            .send()
+            .await?
+            .json()
+            .await?;
+        match login_response.sessionToken {
+            Some(token) => Ok(SecretString::new(token)),
+            None => Err(Error::BFLoginFailure(format!(
+                "loginStatus: {}",
+                login_response.loginStatus
+            ))),
+        }
+    }
+
+    /// Builder for [`BFClientAsync`], mirroring
+    /// [`BFClientBuilder`](super::BFClientBuilder).
+    pub struct BFClientAsyncBuilder {
+        creds: BFCredentials,
+        proxy_uri: Option<String>,
+        rate_limit_per_sec: f64,
+    }
+
+    impl BFClientAsyncBuilder {
+        /// Caps the combined request rate of every call the built client
+        /// makes (`req`, keepalive, login) at `per_sec` tokens/sec.
+        /// Defaults to `DEFAULT_RATE_LIMIT_PER_SEC` (4/sec), Betfair's
+        /// documented safe limit.
+        pub fn rate_limit_per_sec(mut self, per_sec: f64) -> Self {
+            self.rate_limit_per_sec = per_sec;
+            self
+        }
+
+        pub fn build(self) -> Result<BFClientAsync> {
+            let client: reqwest::Client = match &self.proxy_uri {
+                Some(uri) => {
+                    let proxy = reqwest::Proxy::all(uri)?;
+                    Client::builder().proxy(proxy).build()?
+                }
+                None => reqwest::Client::new(),
+            };
+
+            let session_token = Arc::new(RwLock::new(None));
+            let rate_limiter = Arc::new(Mutex::new(AsyncBucket::new(
+                self.rate_limit_per_sec,
+                self.rate_limit_per_sec,
+            )));
+
+            let keepalive_handle = {
+                let session_token = session_token.clone();
+                let creds = self.creds.clone();
+                let proxy_uri = self.proxy_uri.clone();
+                let rate_limiter = rate_limiter.clone();
+                tokio::spawn(BFClientAsync::keepalive_loop(
+                    session_token,
+                    creds,
+                    proxy_uri,
+                    rate_limiter,
+                ))
+            };
+
+            Ok(BFClientAsync {
+                client,
+                session_token,
+                creds: self.creds,
+                proxy_uri: self.proxy_uri,
+                rate_limiter,
+                keepalive_handle,
+            })
+        }
+    }
+
+    async fn keepalive(token: &SecretString, proxy_uri: &Option<String>) -> Result<()> {
+        const KEEPALIVE_URI: &str = "https://identitysso.betfair.com/api/keepAlive";
+
+        let client: Client = match proxy_uri {
+            Some(uri) => {
+                let proxy = reqwest::Proxy::all(uri)?;
+                Client::builder().proxy(proxy).build()?
+            }
+            None => Client::new(),
+        };
+
+        let keep_alive_response: KeepAliveResponse = client
+            .get(KEEPALIVE_URI)
+            .header("Accept", "application/json")
+            .header(
+                "X-Application",
+                format!("schroedinger_{}", rand::random::<u128>()),
+            )
+            .header("X-Authentication", token.expose_secret())
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        match keep_alive_response.status {
+            KeepAliveStatus::SUCCESS => Ok(()),
+            KeepAliveStatus::FAIL => Err(Error::BFKeepAliveFailure(
+                keep_alive_response.error.unwrap(),
+            )),
+        }
+    }
+}
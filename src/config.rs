@@ -0,0 +1,206 @@
+// Configuration for the standalone `botfair` binary: credentials, app key,
+// identity material, optional proxy, and which Betfair region's endpoints to
+// talk to. All of this used to be hardcoded to the author's home directory
+// and to a fixed local SOCKS5 proxy; `ClientBuilder` threads it through
+// instead, the way the OpenEthereum CLI threads its config through to RPC
+// client construction, so the binary can run against any account, region,
+// or machine.
+
+use crate::AnyError;
+use std::env;
+use std::path::PathBuf;
+
+/// Where to read a piece of credential material from.
+pub enum CredentialSource {
+    Literal(String),
+    File(PathBuf),
+    Env(String),
+}
+
+impl CredentialSource {
+    fn resolve(&self) -> Result<String, AnyError> {
+        match self {
+            CredentialSource::Literal(s) => Ok(s.clone()),
+            CredentialSource::File(path) => Ok(std::fs::read_to_string(path)?.replace('\n', "")),
+            CredentialSource::Env(name) => {
+                env::var(name).map_err(|_| AnyError::ConfigMissing(name.clone()))
+            }
+        }
+    }
+}
+
+/// Where to read the PKCS#12 client identity from, for cert-login. See the
+/// `cert` module for generating one in-process instead of uploading one
+/// produced by `openssl`.
+pub enum IdentitySource {
+    File(PathBuf),
+    Der(Vec<u8>),
+}
+
+impl IdentitySource {
+    fn resolve(&self) -> Result<Vec<u8>, AnyError> {
+        match self {
+            IdentitySource::File(path) => Ok(std::fs::read(path)?),
+            IdentitySource::Der(bytes) => Ok(bytes.clone()),
+        }
+    }
+}
+
+/// Which Betfair exchange region to talk to. Each has its own
+/// `identitysso`/API hostnames under a region-specific top-level domain.
+#[derive(Debug, Clone, Copy)]
+pub enum Region {
+    Uk,
+    Australia,
+    Spain,
+    Italy,
+}
+
+impl Region {
+    fn domain(&self) -> &'static str {
+        match self {
+            Region::Uk => "betfair.com",
+            Region::Australia => "betfair.com.au",
+            Region::Spain => "betfair.es",
+            Region::Italy => "betfair.it",
+        }
+    }
+
+    pub fn certlogin_uri(&self) -> String {
+        format!("https://identitysso-cert.{}/api/certlogin", self.domain())
+    }
+
+    pub fn login_uri(&self) -> String {
+        format!("https://identitysso.{}/api/login", self.domain())
+    }
+
+    pub fn keepalive_uri(&self) -> String {
+        format!("https://identitysso.{}/api/keepAlive", self.domain())
+    }
+
+    pub fn logout_uri(&self) -> String {
+        format!("https://identitysso.{}/api/logout", self.domain())
+    }
+
+    pub fn jsonrpc_uri(&self) -> String {
+        format!("https://api.{}/exchange/betting/json-rpc/v1", self.domain())
+    }
+}
+
+/// Everything needed to authenticate and call the exchange, resolved once by
+/// [`ClientBuilder::build`] and reused for every request after.
+pub struct BotfairConfig {
+    pub username: String,
+    pub password: String,
+    pub app_key: String,
+    pub identity_der: Option<Vec<u8>>,
+    pub proxy_uri: Option<String>,
+    pub region: Region,
+}
+
+impl BotfairConfig {
+    /// A plain `reqwest::Client`, routed through the configured proxy if
+    /// one was set. Used for every call except cert-login itself.
+    pub fn http_client(&self) -> Result<reqwest::Client, AnyError> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(uri) = &self.proxy_uri {
+            builder = builder.proxy(reqwest::Proxy::all(uri)?);
+        }
+        Ok(builder.build()?)
+    }
+
+    /// A `reqwest::Client` presenting the configured client identity, for
+    /// the cert-login flow. Fails with [`AnyError::ConfigMissing`] if no
+    /// identity was configured.
+    pub fn http_client_with_identity(&self) -> Result<reqwest::Client, AnyError> {
+        let der = self
+            .identity_der
+            .as_ref()
+            .ok_or_else(|| AnyError::ConfigMissing("identity".to_owned()))?;
+        let identity = reqwest::Identity::from_pkcs12_der(der, "")?;
+        let mut builder = reqwest::Client::builder().identity(identity);
+        if let Some(uri) = &self.proxy_uri {
+            builder = builder.proxy(reqwest::Proxy::all(uri)?);
+        }
+        Ok(builder.build()?)
+    }
+}
+
+/// Builds a [`BotfairConfig`] from whatever combination of literal values,
+/// files, or environment variables the caller has on hand. The SOCKS5 proxy
+/// is opt-in via [`ClientBuilder::proxy_uri`]; omit it to talk to Betfair
+/// directly.
+#[derive(Default)]
+pub struct ClientBuilder {
+    username: Option<CredentialSource>,
+    password: Option<CredentialSource>,
+    app_key: Option<CredentialSource>,
+    identity: Option<IdentitySource>,
+    proxy_uri: Option<String>,
+    region: Option<Region>,
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        ClientBuilder::default()
+    }
+
+    pub fn username(mut self, source: CredentialSource) -> Self {
+        self.username = Some(source);
+        self
+    }
+
+    pub fn password(mut self, source: CredentialSource) -> Self {
+        self.password = Some(source);
+        self
+    }
+
+    pub fn app_key(mut self, source: CredentialSource) -> Self {
+        self.app_key = Some(source);
+        self
+    }
+
+    pub fn identity(mut self, source: IdentitySource) -> Self {
+        self.identity = Some(source);
+        self
+    }
+
+    pub fn proxy_uri(mut self, uri: String) -> Self {
+        self.proxy_uri = Some(uri);
+        self
+    }
+
+    pub fn region(mut self, region: Region) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    pub fn build(self) -> Result<BotfairConfig, AnyError> {
+        let username = self
+            .username
+            .ok_or_else(|| AnyError::ConfigMissing("username".to_owned()))?
+            .resolve()?;
+        let password = self
+            .password
+            .ok_or_else(|| AnyError::ConfigMissing("password".to_owned()))?
+            .resolve()?;
+        let app_key = self
+            .app_key
+            .ok_or_else(|| AnyError::ConfigMissing("app_key".to_owned()))?
+            .resolve()?;
+        let identity_der = self
+            .identity
+            .as_ref()
+            .map(IdentitySource::resolve)
+            .transpose()?;
+
+        Ok(BotfairConfig {
+            username,
+            password,
+            app_key,
+            identity_der,
+            proxy_uri: self.proxy_uri,
+            region: self.region.unwrap_or(Region::Uk),
+        })
+    }
+}
@@ -26,9 +26,8 @@ pub fn listEventTypes(
     let req: listEventTypesRequest = listEventTypesRequest { filter, locale };
     let rpc_request: RpcRequest<listEventTypesRequest> =
         RpcRequest::new("SportsAPING/v1.0/listEventTypes".to_owned(), req);
-    let resp: RpcResponse<Vec<EventTypeResult>> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<Vec<EventTypeResult>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -43,13 +42,11 @@ pub fn listCompetitions(
     filter: MarketFilter,
     locale: Option<String>,
 ) -> Result<Vec<CompetitionResult>, AnyError> {
-    let req: listCompetitionsRequest =
-        listCompetitionsRequest { filter, locale };
+    let req: listCompetitionsRequest = listCompetitionsRequest { filter, locale };
     let rpc_request: RpcRequest<listCompetitionsRequest> =
         RpcRequest::new("SportsAPING/v1.0/listCompetitions".to_owned(), req);
-    let resp: RpcResponse<Vec<CompetitionResult>> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<Vec<CompetitionResult>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -69,9 +66,8 @@ pub fn listTimeRanges(
     };
     let rpc_request: RpcRequest<listTimeRangesRequest> =
         RpcRequest::new("SportsAPING/v1.0/listTimeRanges".to_owned(), req);
-    let resp: RpcResponse<Vec<TimeRangeResult>> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<Vec<TimeRangeResult>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -89,9 +85,8 @@ pub fn listEvents(
     let req: listEventsRequest = listEventsRequest { filter, locale };
     let rpc_request: RpcRequest<listEventsRequest> =
         RpcRequest::new("SportsAPING/v1.0/listEvents".to_owned(), req);
-    let resp: RpcResponse<Vec<EventResult>> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<Vec<EventResult>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -106,13 +101,11 @@ pub fn listMarketTypes(
     filter: MarketFilter,
     locale: Option<String>,
 ) -> Result<Vec<MarketTypeResult>, AnyError> {
-    let req: listMarketTypesRequest =
-        listMarketTypesRequest { filter, locale };
+    let req: listMarketTypesRequest = listMarketTypesRequest { filter, locale };
     let rpc_request: RpcRequest<listMarketTypesRequest> =
         RpcRequest::new("SportsAPING/v1.0/listMarketTypes".to_owned(), req);
-    let resp: RpcResponse<Vec<MarketTypeResult>> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<Vec<MarketTypeResult>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -130,9 +123,8 @@ pub fn listCountries(
     let req: listCountriesRequest = listCountriesRequest { filter, locale };
     let rpc_request: RpcRequest<listCountriesRequest> =
         RpcRequest::new("SportsAPING/v1.0/listCountries".to_owned(), req);
-    let resp: RpcResponse<Vec<CountryCodeResult>> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<Vec<CountryCodeResult>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -150,9 +142,8 @@ pub fn listVenues(
     let req: listVenuesRequest = listVenuesRequest { filter, locale };
     let rpc_request: RpcRequest<listVenuesRequest> =
         RpcRequest::new("SportsAPING/v1.0/listVenues".to_owned(), req);
-    let resp: RpcResponse<Vec<VenueResult>> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<Vec<VenueResult>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -182,13 +173,10 @@ pub fn listMarketCatalogue(
         maxResults,
         locale,
     };
-    let rpc_request: RpcRequest<listMarketCatalogueRequest> = RpcRequest::new(
-        "SportsAPING/v1.0/listMarketCatalogue".to_owned(),
-        req,
-    );
-    let resp: RpcResponse<Vec<MarketCatalogue>> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let rpc_request: RpcRequest<listMarketCatalogueRequest> =
+        RpcRequest::new("SportsAPING/v1.0/listMarketCatalogue".to_owned(), req);
+    let resp: RpcResponse<Vec<MarketCatalogue>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -245,9 +233,95 @@ pub fn listMarketBook(
     };
     let rpc_request: RpcRequest<listMarketBookRequest> =
         RpcRequest::new("SportsAPING/v1.0/listMarketBook".to_owned(), req);
-    let resp: RpcResponse<Vec<MarketBook>> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<Vec<MarketBook>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
+}
+
+/// Builder for [`listMarketBookRequest`], so that the many optional
+/// projections don't all have to be named (and `None`d) at every call site.
+/// The flat [`listMarketBook`] function above remains for backward
+/// compatibility.
+pub struct listMarketBookRequestBuilder {
+    req: listMarketBookRequest,
+}
+
+impl listMarketBookRequest {
+    pub fn builder(marketIds: Vec<MarketId>) -> listMarketBookRequestBuilder {
+        listMarketBookRequestBuilder {
+            req: listMarketBookRequest {
+                marketIds,
+                priceProjection: None,
+                orderProjection: None,
+                matchProjection: None,
+                includeOverallPosition: None,
+                partitionMatchedByStrategyRef: None,
+                customerStrategyRefs: None,
+                currencyCode: None,
+                locale: None,
+                matchedSince: None,
+                betIds: None,
+            },
+        }
+    }
+}
+
+impl listMarketBookRequestBuilder {
+    pub fn price_projection(mut self, price_projection: PriceProjection) -> Self {
+        self.req.priceProjection = Some(price_projection);
+        self
+    }
+    pub fn order_projection(mut self, order_projection: OrderProjection) -> Self {
+        self.req.orderProjection = Some(order_projection);
+        self
+    }
+    pub fn match_projection(mut self, match_projection: MatchProjection) -> Self {
+        self.req.matchProjection = Some(match_projection);
+        self
+    }
+    pub fn include_overall_position(mut self, include_overall_position: bool) -> Self {
+        self.req.includeOverallPosition = Some(include_overall_position);
+        self
+    }
+    pub fn partition_matched_by_strategy_ref(mut self, value: bool) -> Self {
+        self.req.partitionMatchedByStrategyRef = Some(value);
+        self
+    }
+    pub fn customer_strategy_refs(mut self, customer_strategy_refs: Vec<String>) -> Self {
+        self.req.customerStrategyRefs = Some(customer_strategy_refs);
+        self
+    }
+    pub fn currency_code(mut self, currency_code: String) -> Self {
+        self.req.currencyCode = Some(currency_code);
+        self
+    }
+    pub fn locale(mut self, locale: String) -> Self {
+        self.req.locale = Some(locale);
+        self
+    }
+    pub fn matched_since(mut self, matched_since: DateTime<Utc>) -> Self {
+        self.req.matchedSince = Some(matched_since);
+        self
+    }
+    pub fn bet_ids(mut self, bet_ids: Vec<BetId>) -> Self {
+        self.req.betIds = Some(bet_ids);
+        self
+    }
+    pub fn build(self) -> listMarketBookRequest {
+        self.req
+    }
+}
+
+/// As [`listMarketBook`], but taking an already-built
+/// [`listMarketBookRequest`] (see [`listMarketBookRequest::builder`])
+/// instead of one positional argument per field.
+pub fn listMarketBookWithRequest(
+    rb: RequestBuilder,
+    req: listMarketBookRequest,
+) -> Result<Vec<MarketBook>, AnyError> {
+    let rpc_request: RpcRequest<listMarketBookRequest> =
+        RpcRequest::new("SportsAPING/v1.0/listMarketBook".to_owned(), req);
+    let resp: RpcResponse<Vec<MarketBook>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -311,12 +385,101 @@ pub fn listRunnerBook(
     };
     let rpc_request: RpcRequest<listRunnerBookRequest> =
         RpcRequest::new("SportsAPING/v1.0/listRunnerBook".to_owned(), req);
-    let resp: RpcResponse<Vec<MarketBook>> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<Vec<MarketBook>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
-#[derive(Serialize)]
+/// Builder for [`listRunnerBookRequest`], mirroring
+/// [`listMarketBookRequestBuilder`] above.
+pub struct listRunnerBookRequestBuilder {
+    req: listRunnerBookRequest,
+}
+
+impl listRunnerBookRequest {
+    pub fn builder(marketId: MarketId, selectionId: SelectionId) -> listRunnerBookRequestBuilder {
+        listRunnerBookRequestBuilder {
+            req: listRunnerBookRequest {
+                marketId,
+                selectionId,
+                handicap: None,
+                priceProjection: None,
+                orderProjection: None,
+                matchProjection: None,
+                includeOverallPosition: None,
+                partitionMatchedByStrategyRef: None,
+                customerStrategyRefs: None,
+                currencyCode: None,
+                locale: None,
+                matchedSince: None,
+                betIds: None,
+            },
+        }
+    }
+}
+
+impl listRunnerBookRequestBuilder {
+    pub fn handicap(mut self, handicap: f64) -> Self {
+        self.req.handicap = Some(handicap);
+        self
+    }
+    pub fn price_projection(mut self, price_projection: PriceProjection) -> Self {
+        self.req.priceProjection = Some(price_projection);
+        self
+    }
+    pub fn order_projection(mut self, order_projection: OrderProjection) -> Self {
+        self.req.orderProjection = Some(order_projection);
+        self
+    }
+    pub fn match_projection(mut self, match_projection: MatchProjection) -> Self {
+        self.req.matchProjection = Some(match_projection);
+        self
+    }
+    pub fn include_overall_position(mut self, include_overall_position: bool) -> Self {
+        self.req.includeOverallPosition = Some(include_overall_position);
+        self
+    }
+    pub fn partition_matched_by_strategy_ref(mut self, value: bool) -> Self {
+        self.req.partitionMatchedByStrategyRef = Some(value);
+        self
+    }
+    pub fn customer_strategy_refs(mut self, customer_strategy_refs: Vec<String>) -> Self {
+        self.req.customerStrategyRefs = Some(customer_strategy_refs);
+        self
+    }
+    pub fn currency_code(mut self, currency_code: String) -> Self {
+        self.req.currencyCode = Some(currency_code);
+        self
+    }
+    pub fn locale(mut self, locale: String) -> Self {
+        self.req.locale = Some(locale);
+        self
+    }
+    pub fn matched_since(mut self, matched_since: DateTime<Utc>) -> Self {
+        self.req.matchedSince = Some(matched_since);
+        self
+    }
+    pub fn bet_ids(mut self, bet_ids: Vec<BetId>) -> Self {
+        self.req.betIds = Some(bet_ids);
+        self
+    }
+    pub fn build(self) -> listRunnerBookRequest {
+        self.req
+    }
+}
+
+/// As [`listRunnerBook`], but taking an already-built
+/// [`listRunnerBookRequest`] instead of one positional argument per field.
+pub fn listRunnerBookWithRequest(
+    rb: RequestBuilder,
+    req: listRunnerBookRequest,
+) -> Result<Vec<MarketBook>, AnyError> {
+    let rpc_request: RpcRequest<listRunnerBookRequest> =
+        RpcRequest::new("SportsAPING/v1.0/listRunnerBook".to_owned(), req);
+    let resp: RpcResponse<Vec<MarketBook>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
+}
+
+#[derive(Clone, Serialize)]
 pub struct listCurrentOrdersRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub betIds: Option<Vec<BetId>>,
@@ -371,12 +534,11 @@ pub fn listCurrentOrders(
     };
     let rpc_request: RpcRequest<listCurrentOrdersRequest> =
         RpcRequest::new("SportsAPING/v1.0/listCurrentOrders".to_owned(), req);
-    let resp: RpcResponse<CurrentOrderSummaryReport> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<CurrentOrderSummaryReport> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 pub struct listClearedOrdersRequest {
     pub betStatus: BetStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -446,9 +608,175 @@ pub fn listClearedOrders(
     };
     let rpc_request: RpcRequest<listClearedOrdersRequest> =
         RpcRequest::new("SportsAPING/v1.0/listClearedOrders".to_owned(), req);
-    let resp: RpcResponse<ClearedOrderSummaryReport> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<ClearedOrderSummaryReport> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
+}
+
+/// A hard cap on the number of pages [`CurrentOrdersIter`] and
+/// [`ClearedOrdersIter`] will fetch, so that a server that never reports
+/// `moreAvailable: false` cannot spin the caller's loop forever.
+pub const PAGINATION_MAX_PAGES: usize = 1000;
+
+/// An iterator over every [`CurrentOrderSummary`] matching a
+/// `listCurrentOrders` query, transparently re-issuing the request with an
+/// advanced `fromRecord` as each page is exhausted.
+///
+/// Built by [`current_orders_iter`].
+pub struct CurrentOrdersIter<F> {
+    make_rb: F,
+    req: listCurrentOrdersRequest,
+    buffer: std::collections::VecDeque<CurrentOrderSummary>,
+    done: bool,
+    pages_fetched: usize,
+}
+
+impl<F: FnMut() -> RequestBuilder> Iterator for CurrentOrdersIter<F> {
+    type Item = Result<CurrentOrderSummary, AnyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Some(Ok(item));
+            }
+            if self.done || self.pages_fetched >= PAGINATION_MAX_PAGES {
+                return None;
+            }
+            self.pages_fetched += 1;
+            let rb = (self.make_rb)();
+            let req = self.req.clone();
+            match listCurrentOrders(
+                rb,
+                req.betIds,
+                req.marketIds,
+                req.orderProjection,
+                req.customerOrderRefs,
+                req.customerStrategyRefs,
+                req.placedDateRange,
+                req.dateRange,
+                req.orderBy,
+                req.sortDir,
+                req.fromRecord,
+                req.recordCount,
+            ) {
+                Ok(report) => {
+                    let n = report.currentOrders.len();
+                    self.req.fromRecord = Some(self.req.fromRecord.unwrap_or(0) + n as i32);
+                    self.done = !report.moreAvailable || n == 0;
+                    self.buffer.extend(report.currentOrders);
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// Returns an iterator that pages through `listCurrentOrders`, yielding
+/// each [`CurrentOrderSummary`] across as many pages as it takes, with
+/// `page_size` as the `recordCount` of each underlying call.
+///
+/// `make_rb` is invoked once per page to build a fresh `RequestBuilder`,
+/// since a `RequestBuilder` is consumed by `send`. `req.fromRecord` and
+/// `req.recordCount` are overwritten as paging proceeds.
+pub fn current_orders_iter<F: FnMut() -> RequestBuilder>(
+    make_rb: F,
+    mut req: listCurrentOrdersRequest,
+    page_size: i32,
+) -> CurrentOrdersIter<F> {
+    req.recordCount = Some(page_size);
+    req.fromRecord = Some(req.fromRecord.unwrap_or(0));
+    CurrentOrdersIter {
+        make_rb,
+        req,
+        buffer: std::collections::VecDeque::new(),
+        done: false,
+        pages_fetched: 0,
+    }
+}
+
+/// An iterator over every [`ClearedOrderSummary`] matching a
+/// `listClearedOrders` query, transparently re-issuing the request with an
+/// advanced `fromRecord` as each page is exhausted.
+///
+/// Built by [`cleared_orders_iter`].
+pub struct ClearedOrdersIter<F> {
+    make_rb: F,
+    req: listClearedOrdersRequest,
+    buffer: std::collections::VecDeque<ClearedOrderSummary>,
+    done: bool,
+    pages_fetched: usize,
+}
+
+impl<F: FnMut() -> RequestBuilder> Iterator for ClearedOrdersIter<F> {
+    type Item = Result<ClearedOrderSummary, AnyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Some(Ok(item));
+            }
+            if self.done || self.pages_fetched >= PAGINATION_MAX_PAGES {
+                return None;
+            }
+            self.pages_fetched += 1;
+            let rb = (self.make_rb)();
+            let req = self.req.clone();
+            match listClearedOrders(
+                rb,
+                req.betStatus,
+                req.eventTypeIds,
+                req.eventIds,
+                req.marketIds,
+                req.runnerIds,
+                req.betIds,
+                req.customerOrderRefs,
+                req.customerStrategyRefs,
+                req.side,
+                req.settledDateRange,
+                req.groupBy,
+                req.includeItemDescription,
+                req.locale,
+                req.fromRecord,
+                req.recordCount,
+            ) {
+                Ok(report) => {
+                    let n = report.clearedOrders.len();
+                    self.req.fromRecord = Some(self.req.fromRecord.unwrap_or(0) + n as i32);
+                    self.done = !report.moreAvailable || n == 0;
+                    self.buffer.extend(report.clearedOrders);
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// Returns an iterator that pages through `listClearedOrders`, yielding
+/// each [`ClearedOrderSummary`] across as many pages as it takes, with
+/// `page_size` as the `recordCount` of each underlying call.
+///
+/// `make_rb` is invoked once per page to build a fresh `RequestBuilder`,
+/// since a `RequestBuilder` is consumed by `send`. `req.fromRecord` and
+/// `req.recordCount` are overwritten as paging proceeds.
+pub fn cleared_orders_iter<F: FnMut() -> RequestBuilder>(
+    make_rb: F,
+    mut req: listClearedOrdersRequest,
+    page_size: i32,
+) -> ClearedOrdersIter<F> {
+    req.recordCount = Some(page_size);
+    req.fromRecord = Some(req.fromRecord.unwrap_or(0));
+    ClearedOrdersIter {
+        make_rb,
+        req,
+        buffer: std::collections::VecDeque::new(),
+        done: false,
+        pages_fetched: 0,
+    }
 }
 
 #[derive(Serialize)]
@@ -474,6 +802,23 @@ pub fn placeOrders(
     customerStrategyRef: Option<String>,
     r#async: Option<bool>,
 ) -> Result<PlaceExecutionReport, AnyError> {
+    for instruction in &instructions {
+        if let Some(limit_order) = &instruction.limitOrder {
+            if !limit_order.price.is_valid_ladder_tick() {
+                return Err(AnyError::InvalidLadderPrice(limit_order.price));
+            }
+        }
+    }
+    if let Some(customer_strategy_ref) = &customerStrategyRef {
+        if customer_strategy_ref.len() > CUSTOMER_STRATEGY_REF_MAX_LEN {
+            return Err(AnyError::InvalidPlaceInstruction(format!(
+                "customerStrategyRef must be at most {} characters, got {}",
+                CUSTOMER_STRATEGY_REF_MAX_LEN,
+                customer_strategy_ref.len()
+            )));
+        }
+    }
+
     let req: placeOrdersRequest = placeOrdersRequest {
         marketId,
         instructions,
@@ -484,9 +829,8 @@ pub fn placeOrders(
     };
     let rpc_request: RpcRequest<placeOrdersRequest> =
         RpcRequest::new("SportsAPING/v1.0/placeOrders".to_owned(), req);
-    let resp: RpcResponse<PlaceExecutionReport> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<PlaceExecutionReport> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -512,9 +856,8 @@ pub fn cancelOrders(
     };
     let rpc_request: RpcRequest<cancelOrdersRequest> =
         RpcRequest::new("SportsAPING/v1.0/cancelOrders".to_owned(), req);
-    let resp: RpcResponse<CancelExecutionReport> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<CancelExecutionReport> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -537,6 +880,12 @@ pub fn replaceOrders(
     marketVersion: Option<MarketVersion>,
     r#async: Option<bool>,
 ) -> Result<ReplaceExecutionReport, AnyError> {
+    for instruction in &instructions {
+        if !instruction.newPrice.is_valid_ladder_tick() {
+            return Err(AnyError::InvalidLadderPrice(instruction.newPrice));
+        }
+    }
+
     let req: replaceOrdersRequest = replaceOrdersRequest {
         marketId,
         instructions,
@@ -546,9 +895,8 @@ pub fn replaceOrders(
     };
     let rpc_request: RpcRequest<replaceOrdersRequest> =
         RpcRequest::new("SportsAPING/v1.0/replaceOrders".to_owned(), req);
-    let resp: RpcResponse<ReplaceExecutionReport> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<ReplaceExecutionReport> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -572,9 +920,8 @@ pub fn updateOrders(
     };
     let rpc_request: RpcRequest<updateOrdersRequest> =
         RpcRequest::new("SportsAPING/v1.0/updateOrders".to_owned(), req);
-    let resp: RpcResponse<UpdateExecutionReport> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    let resp: RpcResponse<UpdateExecutionReport> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -602,13 +949,9 @@ pub fn listMarketProfitAndLoss(
         netOfCommission,
     };
     let rpc_request: RpcRequest<listMarketProfitAndLossRequest> =
-        RpcRequest::new(
-            "SportsAPING/v1.0/listMarketProfitAndLoss".to_owned(),
-            req,
-        );
-    let resp: RpcResponse<Vec<MarketProfitAndLoss>> =
-        rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+        RpcRequest::new("SportsAPING/v1.0/listMarketProfitAndLoss".to_owned(), req);
+    let resp: RpcResponse<Vec<MarketProfitAndLoss>> = rb.json(&rpc_request).send()?.json()?;
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -627,14 +970,12 @@ pub fn setDefaultExposureLimitForMarketGroups(
             marketGroupType,
             limit,
         };
-    let rpc_request: RpcRequest<
-        setDefaultExposureLimitForMarketGroupsRequest,
-    > = RpcRequest::new(
+    let rpc_request: RpcRequest<setDefaultExposureLimitForMarketGroupsRequest> = RpcRequest::new(
         "SportsAPING/v1.0/setDefaultExposureLimitForMarketGroups".to_owned(),
         req,
     );
     let resp: RpcResponse<String> = rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -650,13 +991,12 @@ pub fn setExposureLimitForMarketGroup(
 ) -> Result<String, AnyError> {
     let req: setExposureLimitForMarketGroupRequest =
         setExposureLimitForMarketGroupRequest { marketGroup, limit };
-    let rpc_request: RpcRequest<setExposureLimitForMarketGroupRequest> =
-        RpcRequest::new(
-            "SportsAPING/v1.0/setExposureLimitForMarketGroup".to_owned(),
-            req,
-        );
+    let rpc_request: RpcRequest<setExposureLimitForMarketGroupRequest> = RpcRequest::new(
+        "SportsAPING/v1.0/setExposureLimitForMarketGroup".to_owned(),
+        req,
+    );
     let resp: RpcResponse<String> = rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -670,15 +1010,12 @@ pub fn removeDefaultExposureLimitForMarketGroups(
 ) -> Result<String, AnyError> {
     let req: removeDefaultExposureLimitForMarketGroupsRequest =
         removeDefaultExposureLimitForMarketGroupsRequest { marketGroupType };
-    let rpc_request: RpcRequest<
-        removeDefaultExposureLimitForMarketGroupsRequest,
-    > = RpcRequest::new(
-        "SportsAPING/v1.0/removeDefaultExposureLimitForMarketGroups"
-            .to_owned(),
+    let rpc_request: RpcRequest<removeDefaultExposureLimitForMarketGroupsRequest> = RpcRequest::new(
+        "SportsAPING/v1.0/removeDefaultExposureLimitForMarketGroups".to_owned(),
         req,
     );
     let resp: RpcResponse<String> = rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -692,13 +1029,12 @@ pub fn removeExposureLimitForMarketGroup(
 ) -> Result<String, AnyError> {
     let req: removeExposureLimitForMarketGroupRequest =
         removeExposureLimitForMarketGroupRequest { marketGroup };
-    let rpc_request: RpcRequest<removeExposureLimitForMarketGroupRequest> =
-        RpcRequest::new(
-            "SportsAPING/v1.0/removeExposureLimitForMarketGroup".to_owned(),
-            req,
-        );
+    let rpc_request: RpcRequest<removeExposureLimitForMarketGroupRequest> = RpcRequest::new(
+        "SportsAPING/v1.0/removeExposureLimitForMarketGroup".to_owned(),
+        req,
+    );
     let resp: RpcResponse<String> = rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -714,19 +1050,17 @@ pub fn listExposureLimitsForMarketGroups(
     marketGroupTypeFilter: Option<MarketGroupType>,
     marketGroupFilter: Option<Vec<MarketGroup>>,
 ) -> Result<Vec<ExposureLimitsForMarketGroups>, AnyError> {
-    let req: listExposureLimitsForMarketGroupsRequest =
-        listExposureLimitsForMarketGroupsRequest {
-            marketGroupTypeFilter,
-            marketGroupFilter,
-        };
-    let rpc_request: RpcRequest<listExposureLimitsForMarketGroupsRequest> =
-        RpcRequest::new(
-            "SportsAPING/v1.0/listExposureLimitsForMarketGroups".to_owned(),
-            req,
-        );
+    let req: listExposureLimitsForMarketGroupsRequest = listExposureLimitsForMarketGroupsRequest {
+        marketGroupTypeFilter,
+        marketGroupFilter,
+    };
+    let rpc_request: RpcRequest<listExposureLimitsForMarketGroupsRequest> = RpcRequest::new(
+        "SportsAPING/v1.0/listExposureLimitsForMarketGroups".to_owned(),
+        req,
+    );
     let resp: RpcResponse<Vec<ExposureLimitsForMarketGroups>> =
         rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -738,23 +1072,20 @@ pub fn unblockMarketGroup(
     rb: RequestBuilder,
     marketGroup: MarketGroup,
 ) -> Result<String, AnyError> {
-    let req: unblockMarketGroupRequest =
-        unblockMarketGroupRequest { marketGroup };
+    let req: unblockMarketGroupRequest = unblockMarketGroupRequest { marketGroup };
     let rpc_request: RpcRequest<unblockMarketGroupRequest> =
         RpcRequest::new("SportsAPING/v1.0/unblockMarketGroup".to_owned(), req);
     let resp: RpcResponse<String> = rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    Ok(resp.into_inner()?)
 }
 
-pub fn getExposureReuseEnabledEvents(
-    rb: RequestBuilder,
-) -> Result<Vec<i64>, AnyError> {
+pub fn getExposureReuseEnabledEvents(rb: RequestBuilder) -> Result<Vec<i64>, AnyError> {
     let rpc_request: RpcRequest<()> = RpcRequest::new(
         "SportsAPING/v1.0/getExposureReuseEnabledEvents".to_owned(),
         (),
     );
     let resp: RpcResponse<Vec<i64>> = rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -768,13 +1099,12 @@ pub fn addExposureReuseEnabledEvents(
 ) -> Result<String, AnyError> {
     let req: addExposureReuseEnabledEventsRequest =
         addExposureReuseEnabledEventsRequest { eventIds };
-    let rpc_request: RpcRequest<addExposureReuseEnabledEventsRequest> =
-        RpcRequest::new(
-            "SportsAPING/v1.0/addExposureReuseEnabledEvents".to_owned(),
-            req,
-        );
+    let rpc_request: RpcRequest<addExposureReuseEnabledEventsRequest> = RpcRequest::new(
+        "SportsAPING/v1.0/addExposureReuseEnabledEvents".to_owned(),
+        req,
+    );
     let resp: RpcResponse<String> = rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    Ok(resp.into_inner()?)
 }
 
 #[derive(Serialize)]
@@ -788,13 +1118,12 @@ pub fn removeExposureReuseEnabledEvents(
 ) -> Result<String, AnyError> {
     let req: removeExposureReuseEnabledEventsRequest =
         removeExposureReuseEnabledEventsRequest { eventIds };
-    let rpc_request: RpcRequest<removeExposureReuseEnabledEventsRequest> =
-        RpcRequest::new(
-            "SportsAPING/v1.0/removeExposureReuseEnabledEvents".to_owned(),
-            req,
-        );
+    let rpc_request: RpcRequest<removeExposureReuseEnabledEventsRequest> = RpcRequest::new(
+        "SportsAPING/v1.0/removeExposureReuseEnabledEvents".to_owned(),
+        req,
+    );
     let resp: RpcResponse<String> = rb.json(&rpc_request).send()?.json()?;
-    Ok(resp.into_inner())
+    Ok(resp.into_inner()?)
 }
 #[derive(Debug, Deserialize, Serialize)]
 pub enum MarketProjection {
@@ -820,7 +1149,7 @@ pub enum MatchProjection {
     ROLLED_UP_BY_PRICE,
     ROLLED_UP_BY_AVG_PRICE,
 }
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum OrderProjection {
     ALL,
     EXECUTABLE,
@@ -858,25 +1187,149 @@ pub type EventTypeId = String;
 pub type CountryCode = String;
 pub type ExchangeId = String;
 pub type CompetitionId = String;
-pub type Price = f64;
-pub type Size = f64;
+
+/// The numeric representation backing every monetary/price field (`Price`,
+/// `Size`, commission, profit, exposure limits). Defaults to `f64`; build
+/// with the `decimal` feature to switch to `rust_decimal::Decimal` (paired
+/// with the `rust_decimal/serde-float` crate feature, so values still
+/// round-trip as bare JSON numbers over the wire) for stake/liability
+/// arithmetic free of floating-point drift.
+#[cfg(not(feature = "decimal"))]
+pub type Amount = f64;
+#[cfg(feature = "decimal")]
+pub type Amount = rust_decimal::Decimal;
+
+/// Converts an `Amount` to `f64` for local arithmetic (e.g. ladder checks, P&L
+/// projections). See [`amount_from_f64`] for the reverse direction.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn amount_to_f64(a: Amount) -> f64 {
+    a
+}
+#[cfg(feature = "decimal")]
+pub(crate) fn amount_to_f64(a: Amount) -> f64 {
+    use rust_decimal::prelude::ToPrimitive;
+    a.to_f64().unwrap_or(0.0)
+}
+
+/// Builds an `Amount` from an `f64` computed during local arithmetic.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn amount_from_f64(f: f64) -> Amount {
+    f
+}
+#[cfg(feature = "decimal")]
+pub(crate) fn amount_from_f64(f: f64) -> Amount {
+    Amount::try_from(f).unwrap_or_default()
+}
+
+/// Odds, as accepted by the exchange. Unlike a bare `f64`, `Price` carries
+/// methods to check and snap against Betfair's fixed odds ladder (see
+/// [`crate::ladder`]), since only ~350 discrete rungs between 1.01 and 1000
+/// are ever accepted server-side.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Price(pub Amount);
+
+impl Price {
+    /// `true` if this price sits exactly on a valid ladder rung.
+    pub fn is_valid_ladder_tick(&self) -> bool {
+        crate::ladder::is_valid(amount_to_f64(self.0))
+    }
+
+    /// Snaps this price to the nearest valid ladder rung in the given
+    /// direction, clamping to the ladder's bounds if it falls outside them.
+    pub fn round_to_ladder(&self, direction: crate::ladder::RoundDirection) -> Price {
+        crate::ladder::round_to_tick(amount_to_f64(self.0), direction).into()
+    }
+}
+
+impl From<f64> for Price {
+    fn from(p: f64) -> Self {
+        Price(amount_from_f64(p))
+    }
+}
+
+pub type Size = Amount;
 pub type BetId = String;
 pub type MatchId = String;
 pub type CustomerOrderRef = String;
 pub type CustomerStrategyRef = String;
-#[derive(Debug, Deserialize, Serialize)]
+/// Whether an order/bet backs or lays a selection. Any value the crate
+/// doesn't yet know the name of is kept as [`Side::Unknown`] rather than
+/// failing deserialization, since Betfair has historically added new
+/// statuses without a major version bump.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Side {
     BACK,
     LAY,
-}
-#[derive(Debug, Deserialize, Serialize)]
+    Unknown(String),
+}
+impl<'de> Deserialize<'de> for Side {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BACK" => Side::BACK,
+            "LAY" => Side::LAY,
+            _ => Side::Unknown(s),
+        })
+    }
+}
+impl Serialize for Side {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            Side::BACK => "BACK",
+            Side::LAY => "LAY",
+            Side::Unknown(s) => s,
+        })
+    }
+}
+/// The lifecycle state of a single order. Any value the crate doesn't yet
+/// know the name of is kept as [`OrderStatus::Unknown`] rather than failing
+/// deserialization, since Betfair has historically added new statuses
+/// without a major version bump.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OrderStatus {
     PENDING,
     EXECUTION_COMPLETE,
     EXECUTABLE,
     EXPIRED,
-}
-#[derive(Debug, Deserialize, Serialize)]
+    Unknown(String),
+}
+impl<'de> Deserialize<'de> for OrderStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "PENDING" => OrderStatus::PENDING,
+            "EXECUTION_COMPLETE" => OrderStatus::EXECUTION_COMPLETE,
+            "EXECUTABLE" => OrderStatus::EXECUTABLE,
+            "EXPIRED" => OrderStatus::EXPIRED,
+            _ => OrderStatus::Unknown(s),
+        })
+    }
+}
+impl Serialize for OrderStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            OrderStatus::PENDING => "PENDING",
+            OrderStatus::EXECUTION_COMPLETE => "EXECUTION_COMPLETE",
+            OrderStatus::EXECUTABLE => "EXECUTABLE",
+            OrderStatus::EXPIRED => "EXPIRED",
+            OrderStatus::Unknown(s) => s,
+        })
+    }
+}
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum OrderBy {
     BY_BET,
     BY_MARKET,
@@ -885,16 +1338,48 @@ pub enum OrderBy {
     BY_VOID_TIME,
     BY_SETTLED_TIME,
 }
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum SortDir {
     EARLIEST_TO_LATEST,
     LATEST_TO_EARLIEST,
 }
-#[derive(Debug, Deserialize, Serialize)]
+/// The execution model for a placed order. Any value the crate doesn't yet
+/// know the name of is kept as [`OrderType::Unknown`] rather than failing
+/// deserialization, since Betfair has historically added new statuses
+/// without a major version bump.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OrderType {
     LIMIT,
     LIMIT_ON_CLOSE,
     MARKET_ON_CLOSE,
+    Unknown(String),
+}
+impl<'de> Deserialize<'de> for OrderType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "LIMIT" => OrderType::LIMIT,
+            "LIMIT_ON_CLOSE" => OrderType::LIMIT_ON_CLOSE,
+            "MARKET_ON_CLOSE" => OrderType::MARKET_ON_CLOSE,
+            _ => OrderType::Unknown(s),
+        })
+    }
+}
+impl Serialize for OrderType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            OrderType::LIMIT => "LIMIT",
+            OrderType::LIMIT_ON_CLOSE => "LIMIT_ON_CLOSE",
+            OrderType::MARKET_ON_CLOSE => "MARKET_ON_CLOSE",
+            OrderType::Unknown(s) => s,
+        })
+    }
 }
 #[derive(Debug, Deserialize, Serialize)]
 pub enum MarketSort {
@@ -914,14 +1399,50 @@ pub enum MarketBettingType {
     ASIAN_HANDICAP_SINGLE_LINE,
     FIXED_ODDS,
 }
-#[derive(Debug, Deserialize, Serialize)]
+/// The outcome of an `*ExecutionReport` as a whole. Any value the crate
+/// doesn't yet know the name of is kept as [`ExecutionReportStatus::Unknown`]
+/// rather than failing deserialization, since Betfair has historically added
+/// new statuses without a major version bump.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ExecutionReportStatus {
     SUCCESS,
     FAILURE,
     PROCESSED_WITH_ERRORS,
     TIMEOUT,
-}
-#[derive(Debug, Deserialize, Serialize)]
+    Unknown(String),
+}
+impl<'de> Deserialize<'de> for ExecutionReportStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SUCCESS" => ExecutionReportStatus::SUCCESS,
+            "FAILURE" => ExecutionReportStatus::FAILURE,
+            "PROCESSED_WITH_ERRORS" => ExecutionReportStatus::PROCESSED_WITH_ERRORS,
+            "TIMEOUT" => ExecutionReportStatus::TIMEOUT,
+            _ => ExecutionReportStatus::Unknown(s),
+        })
+    }
+}
+impl Serialize for ExecutionReportStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            ExecutionReportStatus::SUCCESS => "SUCCESS",
+            ExecutionReportStatus::FAILURE => "FAILURE",
+            ExecutionReportStatus::PROCESSED_WITH_ERRORS => "PROCESSED_WITH_ERRORS",
+            ExecutionReportStatus::TIMEOUT => "TIMEOUT",
+            ExecutionReportStatus::Unknown(s) => s,
+        })
+    }
+}
+/// As [`ExecutionReportStatus`], but for why an `*ExecutionReport` as a
+/// whole failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ExecutionReportErrorCode {
     ERROR_IN_MATCHER,
     PROCESSED_WITH_ERRORS,
@@ -947,6 +1468,84 @@ pub enum ExecutionReportErrorCode {
     EVENT_EXPOSURE_LIMIT_EXCEEDED,
     EVENT_MATCHED_EXPOSURE_LIMIT_EXCEEDED,
     EVENT_BLOCKED,
+    Unknown(String),
+}
+impl<'de> Deserialize<'de> for ExecutionReportErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ERROR_IN_MATCHER" => ExecutionReportErrorCode::ERROR_IN_MATCHER,
+            "PROCESSED_WITH_ERRORS" => ExecutionReportErrorCode::PROCESSED_WITH_ERRORS,
+            "BET_ACTION_ERROR" => ExecutionReportErrorCode::BET_ACTION_ERROR,
+            "INVALID_ACCOUNT_STATE" => ExecutionReportErrorCode::INVALID_ACCOUNT_STATE,
+            "INVALID_WALLET_STATUS" => ExecutionReportErrorCode::INVALID_WALLET_STATUS,
+            "INSUFFICIENT_FUNDS" => ExecutionReportErrorCode::INSUFFICIENT_FUNDS,
+            "LOSS_LIMIT_EXCEEDED" => ExecutionReportErrorCode::LOSS_LIMIT_EXCEEDED,
+            "MARKET_SUSPENDED" => ExecutionReportErrorCode::MARKET_SUSPENDED,
+            "MARKET_NOT_OPEN_FOR_BETTING" => ExecutionReportErrorCode::MARKET_NOT_OPEN_FOR_BETTING,
+            "DUPLICATE_TRANSACTION" => ExecutionReportErrorCode::DUPLICATE_TRANSACTION,
+            "INVALID_ORDER" => ExecutionReportErrorCode::INVALID_ORDER,
+            "INVALID_MARKET_ID" => ExecutionReportErrorCode::INVALID_MARKET_ID,
+            "PERMISSION_DENIED" => ExecutionReportErrorCode::PERMISSION_DENIED,
+            "DUPLICATE_BETIDS" => ExecutionReportErrorCode::DUPLICATE_BETIDS,
+            "NO_ACTION_REQUIRED" => ExecutionReportErrorCode::NO_ACTION_REQUIRED,
+            "SERVICE_UNAVAILABLE" => ExecutionReportErrorCode::SERVICE_UNAVAILABLE,
+            "REJECTED_BY_REGULATOR" => ExecutionReportErrorCode::REJECTED_BY_REGULATOR,
+            "NO_CHASING" => ExecutionReportErrorCode::NO_CHASING,
+            "REGULATOR_IS_NOT_AVAILABLE" => ExecutionReportErrorCode::REGULATOR_IS_NOT_AVAILABLE,
+            "TOO_MANY_INSTRUCTIONS" => ExecutionReportErrorCode::TOO_MANY_INSTRUCTIONS,
+            "INVALID_MARKET_VERSION" => ExecutionReportErrorCode::INVALID_MARKET_VERSION,
+            "EVENT_EXPOSURE_LIMIT_EXCEEDED" => {
+                ExecutionReportErrorCode::EVENT_EXPOSURE_LIMIT_EXCEEDED
+            }
+            "EVENT_MATCHED_EXPOSURE_LIMIT_EXCEEDED" => {
+                ExecutionReportErrorCode::EVENT_MATCHED_EXPOSURE_LIMIT_EXCEEDED
+            }
+            "EVENT_BLOCKED" => ExecutionReportErrorCode::EVENT_BLOCKED,
+            _ => ExecutionReportErrorCode::Unknown(s),
+        })
+    }
+}
+impl Serialize for ExecutionReportErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            ExecutionReportErrorCode::ERROR_IN_MATCHER => "ERROR_IN_MATCHER",
+            ExecutionReportErrorCode::PROCESSED_WITH_ERRORS => "PROCESSED_WITH_ERRORS",
+            ExecutionReportErrorCode::BET_ACTION_ERROR => "BET_ACTION_ERROR",
+            ExecutionReportErrorCode::INVALID_ACCOUNT_STATE => "INVALID_ACCOUNT_STATE",
+            ExecutionReportErrorCode::INVALID_WALLET_STATUS => "INVALID_WALLET_STATUS",
+            ExecutionReportErrorCode::INSUFFICIENT_FUNDS => "INSUFFICIENT_FUNDS",
+            ExecutionReportErrorCode::LOSS_LIMIT_EXCEEDED => "LOSS_LIMIT_EXCEEDED",
+            ExecutionReportErrorCode::MARKET_SUSPENDED => "MARKET_SUSPENDED",
+            ExecutionReportErrorCode::MARKET_NOT_OPEN_FOR_BETTING => "MARKET_NOT_OPEN_FOR_BETTING",
+            ExecutionReportErrorCode::DUPLICATE_TRANSACTION => "DUPLICATE_TRANSACTION",
+            ExecutionReportErrorCode::INVALID_ORDER => "INVALID_ORDER",
+            ExecutionReportErrorCode::INVALID_MARKET_ID => "INVALID_MARKET_ID",
+            ExecutionReportErrorCode::PERMISSION_DENIED => "PERMISSION_DENIED",
+            ExecutionReportErrorCode::DUPLICATE_BETIDS => "DUPLICATE_BETIDS",
+            ExecutionReportErrorCode::NO_ACTION_REQUIRED => "NO_ACTION_REQUIRED",
+            ExecutionReportErrorCode::SERVICE_UNAVAILABLE => "SERVICE_UNAVAILABLE",
+            ExecutionReportErrorCode::REJECTED_BY_REGULATOR => "REJECTED_BY_REGULATOR",
+            ExecutionReportErrorCode::NO_CHASING => "NO_CHASING",
+            ExecutionReportErrorCode::REGULATOR_IS_NOT_AVAILABLE => "REGULATOR_IS_NOT_AVAILABLE",
+            ExecutionReportErrorCode::TOO_MANY_INSTRUCTIONS => "TOO_MANY_INSTRUCTIONS",
+            ExecutionReportErrorCode::INVALID_MARKET_VERSION => "INVALID_MARKET_VERSION",
+            ExecutionReportErrorCode::EVENT_EXPOSURE_LIMIT_EXCEEDED => {
+                "EVENT_EXPOSURE_LIMIT_EXCEEDED"
+            }
+            ExecutionReportErrorCode::EVENT_MATCHED_EXPOSURE_LIMIT_EXCEEDED => {
+                "EVENT_MATCHED_EXPOSURE_LIMIT_EXCEEDED"
+            }
+            ExecutionReportErrorCode::EVENT_BLOCKED => "EVENT_BLOCKED",
+            ExecutionReportErrorCode::Unknown(s) => s,
+        })
+    }
 }
 #[derive(Debug, Deserialize, Serialize)]
 pub enum PersistenceType {
@@ -954,13 +1553,45 @@ pub enum PersistenceType {
     PERSIST,
     MARKET_ON_CLOSE,
 }
-#[derive(Debug, Deserialize, Serialize)]
+/// As [`ExecutionReportStatus`], but for an individual instruction within an
+/// `*ExecutionReport`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InstructionReportStatus {
     SUCCESS,
     FAILURE,
     TIMEOUT,
-}
-#[derive(Debug, Deserialize, Serialize)]
+    Unknown(String),
+}
+impl<'de> Deserialize<'de> for InstructionReportStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SUCCESS" => InstructionReportStatus::SUCCESS,
+            "FAILURE" => InstructionReportStatus::FAILURE,
+            "TIMEOUT" => InstructionReportStatus::TIMEOUT,
+            _ => InstructionReportStatus::Unknown(s),
+        })
+    }
+}
+impl Serialize for InstructionReportStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            InstructionReportStatus::SUCCESS => "SUCCESS",
+            InstructionReportStatus::FAILURE => "FAILURE",
+            InstructionReportStatus::TIMEOUT => "TIMEOUT",
+            InstructionReportStatus::Unknown(s) => s,
+        })
+    }
+}
+/// As [`ExecutionReportErrorCode`], but for why an individual instruction
+/// within an `*ExecutionReport` failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InstructionReportErrorCode {
     INVALID_BET_SIZE,
     INVALID_RUNNER,
@@ -990,6 +1621,108 @@ pub enum InstructionReportErrorCode {
     UNEXPECTED_MIN_FILL_SIZE,
     INVALID_CUSTOMER_STRATEGY_REF,
     BET_LAPSED_PRICE_IMPROVEMENT_TOO_LARGE,
+    Unknown(String),
+}
+impl<'de> Deserialize<'de> for InstructionReportErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "INVALID_BET_SIZE" => InstructionReportErrorCode::INVALID_BET_SIZE,
+            "INVALID_RUNNER" => InstructionReportErrorCode::INVALID_RUNNER,
+            "BET_TAKEN_OR_LAPSED" => InstructionReportErrorCode::BET_TAKEN_OR_LAPSED,
+            "BET_IN_PROGRESS" => InstructionReportErrorCode::BET_IN_PROGRESS,
+            "RUNNER_REMOVED" => InstructionReportErrorCode::RUNNER_REMOVED,
+            "MARKET_NOT_OPEN_FOR_BETTING" => {
+                InstructionReportErrorCode::MARKET_NOT_OPEN_FOR_BETTING
+            }
+            "LOSS_LIMIT_EXCEEDED" => InstructionReportErrorCode::LOSS_LIMIT_EXCEEDED,
+            "MARKET_NOT_OPEN_FOR_BSP_BETTING" => {
+                InstructionReportErrorCode::MARKET_NOT_OPEN_FOR_BSP_BETTING
+            }
+            "INVALID_PRICE_EDIT" => InstructionReportErrorCode::INVALID_PRICE_EDIT,
+            "INVALID_ODDS" => InstructionReportErrorCode::INVALID_ODDS,
+            "INSUFFICIENT_FUNDS" => InstructionReportErrorCode::INSUFFICIENT_FUNDS,
+            "INVALID_PERSISTENCE_TYPE" => InstructionReportErrorCode::INVALID_PERSISTENCE_TYPE,
+            "ERROR_IN_MATCHER" => InstructionReportErrorCode::ERROR_IN_MATCHER,
+            "INVALID_BACK_LAY_COMBINATION" => {
+                InstructionReportErrorCode::INVALID_BACK_LAY_COMBINATION
+            }
+            "ERROR_IN_ORDER" => InstructionReportErrorCode::ERROR_IN_ORDER,
+            "INVALID_BID_TYPE" => InstructionReportErrorCode::INVALID_BID_TYPE,
+            "INVALID_BET_ID" => InstructionReportErrorCode::INVALID_BET_ID,
+            "CANCELLED_NOT_PLACED" => InstructionReportErrorCode::CANCELLED_NOT_PLACED,
+            "RELATED_ACTION_FAILED" => InstructionReportErrorCode::RELATED_ACTION_FAILED,
+            "NO_ACTION_REQUIRED" => InstructionReportErrorCode::NO_ACTION_REQUIRED,
+            "INVALID_MIN_FILL_SIZE" => InstructionReportErrorCode::INVALID_MIN_FILL_SIZE,
+            "INVALID_CUSTOMER_ORDER_REF" => InstructionReportErrorCode::INVALID_CUSTOMER_ORDER_REF,
+            "TIME_IN_FORCE_CONFLICT" => InstructionReportErrorCode::TIME_IN_FORCE_CONFLICT,
+            "UNEXPECTED_PERSISTENCE_TYPE" => {
+                InstructionReportErrorCode::UNEXPECTED_PERSISTENCE_TYPE
+            }
+            "INVALID_ORDER_TYPE" => InstructionReportErrorCode::INVALID_ORDER_TYPE,
+            "UNEXPECTED_MIN_FILL_SIZE" => InstructionReportErrorCode::UNEXPECTED_MIN_FILL_SIZE,
+            "INVALID_CUSTOMER_STRATEGY_REF" => {
+                InstructionReportErrorCode::INVALID_CUSTOMER_STRATEGY_REF
+            }
+            "BET_LAPSED_PRICE_IMPROVEMENT_TOO_LARGE" => {
+                InstructionReportErrorCode::BET_LAPSED_PRICE_IMPROVEMENT_TOO_LARGE
+            }
+            _ => InstructionReportErrorCode::Unknown(s),
+        })
+    }
+}
+impl Serialize for InstructionReportErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            InstructionReportErrorCode::INVALID_BET_SIZE => "INVALID_BET_SIZE",
+            InstructionReportErrorCode::INVALID_RUNNER => "INVALID_RUNNER",
+            InstructionReportErrorCode::BET_TAKEN_OR_LAPSED => "BET_TAKEN_OR_LAPSED",
+            InstructionReportErrorCode::BET_IN_PROGRESS => "BET_IN_PROGRESS",
+            InstructionReportErrorCode::RUNNER_REMOVED => "RUNNER_REMOVED",
+            InstructionReportErrorCode::MARKET_NOT_OPEN_FOR_BETTING => {
+                "MARKET_NOT_OPEN_FOR_BETTING"
+            }
+            InstructionReportErrorCode::LOSS_LIMIT_EXCEEDED => "LOSS_LIMIT_EXCEEDED",
+            InstructionReportErrorCode::MARKET_NOT_OPEN_FOR_BSP_BETTING => {
+                "MARKET_NOT_OPEN_FOR_BSP_BETTING"
+            }
+            InstructionReportErrorCode::INVALID_PRICE_EDIT => "INVALID_PRICE_EDIT",
+            InstructionReportErrorCode::INVALID_ODDS => "INVALID_ODDS",
+            InstructionReportErrorCode::INSUFFICIENT_FUNDS => "INSUFFICIENT_FUNDS",
+            InstructionReportErrorCode::INVALID_PERSISTENCE_TYPE => "INVALID_PERSISTENCE_TYPE",
+            InstructionReportErrorCode::ERROR_IN_MATCHER => "ERROR_IN_MATCHER",
+            InstructionReportErrorCode::INVALID_BACK_LAY_COMBINATION => {
+                "INVALID_BACK_LAY_COMBINATION"
+            }
+            InstructionReportErrorCode::ERROR_IN_ORDER => "ERROR_IN_ORDER",
+            InstructionReportErrorCode::INVALID_BID_TYPE => "INVALID_BID_TYPE",
+            InstructionReportErrorCode::INVALID_BET_ID => "INVALID_BET_ID",
+            InstructionReportErrorCode::CANCELLED_NOT_PLACED => "CANCELLED_NOT_PLACED",
+            InstructionReportErrorCode::RELATED_ACTION_FAILED => "RELATED_ACTION_FAILED",
+            InstructionReportErrorCode::NO_ACTION_REQUIRED => "NO_ACTION_REQUIRED",
+            InstructionReportErrorCode::INVALID_MIN_FILL_SIZE => "INVALID_MIN_FILL_SIZE",
+            InstructionReportErrorCode::INVALID_CUSTOMER_ORDER_REF => "INVALID_CUSTOMER_ORDER_REF",
+            InstructionReportErrorCode::TIME_IN_FORCE_CONFLICT => "TIME_IN_FORCE_CONFLICT",
+            InstructionReportErrorCode::UNEXPECTED_PERSISTENCE_TYPE => {
+                "UNEXPECTED_PERSISTENCE_TYPE"
+            }
+            InstructionReportErrorCode::INVALID_ORDER_TYPE => "INVALID_ORDER_TYPE",
+            InstructionReportErrorCode::UNEXPECTED_MIN_FILL_SIZE => "UNEXPECTED_MIN_FILL_SIZE",
+            InstructionReportErrorCode::INVALID_CUSTOMER_STRATEGY_REF => {
+                "INVALID_CUSTOMER_STRATEGY_REF"
+            }
+            InstructionReportErrorCode::BET_LAPSED_PRICE_IMPROVEMENT_TOO_LARGE => {
+                "BET_LAPSED_PRICE_IMPROVEMENT_TOO_LARGE"
+            }
+            InstructionReportErrorCode::Unknown(s) => s,
+        })
+    }
 }
 #[derive(Debug, Deserialize, Serialize)]
 pub enum RollupModel {
@@ -998,7 +1731,7 @@ pub enum RollupModel {
     MANAGED_LIABILITY,
     NONE,
 }
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum GroupBy {
     EVENT_TYPE,
     EVENT,
@@ -1008,7 +1741,7 @@ pub enum GroupBy {
     BET,
     STRATEGY,
 }
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum BetStatus {
     SETTLED,
     VOIDED,
@@ -1040,7 +1773,7 @@ pub enum LimitBreachActionType {
     STOP_BETTING,
     TEAR_DOWN_MARKET_GROUP,
 }
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct MarketFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     textQuery: Option<String>,
@@ -1075,6 +1808,78 @@ pub struct MarketFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     raceTypes: Option<Vec<String>>,
 }
+
+/// Ergonomic builder-style setters for [`MarketFilter`], so that callers don't
+/// have to name every field of a type that is almost always mostly `None`.
+/// Use together with `MarketFilter::default()`, e.g.
+/// `MarketFilter::default().event_type_ids(vec!["7".to_owned()]).in_play_only(true)`.
+impl MarketFilter {
+    pub fn text_query(mut self, text_query: String) -> Self {
+        self.textQuery = Some(text_query);
+        self
+    }
+    pub fn exchange_ids(mut self, exchange_ids: Vec<ExchangeId>) -> Self {
+        self.exchangeIds = Some(exchange_ids);
+        self
+    }
+    pub fn event_type_ids(mut self, event_type_ids: Vec<EventTypeId>) -> Self {
+        self.eventTypeIds = Some(event_type_ids);
+        self
+    }
+    pub fn event_ids(mut self, event_ids: Vec<EventId>) -> Self {
+        self.eventIds = Some(event_ids);
+        self
+    }
+    pub fn competition_ids(mut self, competition_ids: Vec<CompetitionId>) -> Self {
+        self.competitionIds = Some(competition_ids);
+        self
+    }
+    pub fn market_ids(mut self, market_ids: Vec<MarketId>) -> Self {
+        self.marketIds = Some(market_ids);
+        self
+    }
+    pub fn venues(mut self, venues: Vec<Venue>) -> Self {
+        self.venues = Some(venues);
+        self
+    }
+    pub fn bsp_only(mut self, bsp_only: bool) -> Self {
+        self.bspOnly = Some(bsp_only);
+        self
+    }
+    pub fn turn_in_play_enabled(mut self, turn_in_play_enabled: bool) -> Self {
+        self.turnInPlayEnabled = Some(turn_in_play_enabled);
+        self
+    }
+    pub fn in_play_only(mut self, in_play_only: bool) -> Self {
+        self.inPlayOnly = Some(in_play_only);
+        self
+    }
+    pub fn market_betting_types(mut self, market_betting_types: Vec<MarketBettingType>) -> Self {
+        self.marketBettingTypes = Some(market_betting_types);
+        self
+    }
+    pub fn market_countries(mut self, market_countries: Vec<CountryCode>) -> Self {
+        self.marketCountries = Some(market_countries);
+        self
+    }
+    pub fn market_type_codes(mut self, market_type_codes: Vec<MarketType>) -> Self {
+        self.marketTypeCodes = Some(market_type_codes);
+        self
+    }
+    pub fn market_start_time(mut self, market_start_time: TimeRange) -> Self {
+        self.marketStartTime = Some(market_start_time);
+        self
+    }
+    pub fn with_orders(mut self, with_orders: Vec<OrderStatus>) -> Self {
+        self.withOrders = Some(with_orders);
+        self
+    }
+    pub fn race_types(mut self, race_types: Vec<String>) -> Self {
+        self.raceTypes = Some(race_types);
+        self
+    }
+}
+
 /// Information about a market
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MarketCatalogue {
@@ -1274,7 +2079,7 @@ pub struct VenueResult {
     marketCount: Option<i32>,
 }
 /// TimeRange
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TimeRange {
     #[serde(skip_serializing_if = "Option::is_none")]
     from: Option<DateTime<Utc>>,
@@ -1461,6 +2266,38 @@ pub struct CurrentOrderSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
     customerStrategyRef: Option<String>,
 }
+/// Whether a settled bet won or lost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BetOutcome {
+    WON,
+    LOST,
+    Unknown(String),
+}
+impl<'de> Deserialize<'de> for BetOutcome {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "WON" => BetOutcome::WON,
+            "LOST" => BetOutcome::LOST,
+            _ => BetOutcome::Unknown(s),
+        })
+    }
+}
+impl Serialize for BetOutcome {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            BetOutcome::WON => "WON",
+            BetOutcome::LOST => "LOST",
+            BetOutcome::Unknown(s) => s,
+        })
+    }
+}
 /// Summary of a cleared order.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ClearedOrderSummary {
@@ -1471,7 +2308,7 @@ pub struct ClearedOrderSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
     marketId: Option<MarketId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    selectionId: Option<SelectionId>,
+    pub(crate) selectionId: Option<SelectionId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     handicap: Option<Handicap>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1479,15 +2316,15 @@ pub struct ClearedOrderSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
     placedDate: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    persistenceType: Option<String>,
+    persistenceType: Option<PersistenceType>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    orderType: Option<String>,
+    orderType: Option<OrderType>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    side: Option<String>,
+    pub(crate) side: Option<Side>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    itemDescription: Option<ItemDescription>,
+    pub(crate) itemDescription: Option<ItemDescription>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    betOutcome: Option<String>,
+    betOutcome: Option<BetOutcome>,
     #[serde(skip_serializing_if = "Option::is_none")]
     priceRequested: Option<Price>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1499,11 +2336,11 @@ pub struct ClearedOrderSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
     commission: Option<Size>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    priceMatched: Option<Price>,
+    pub(crate) priceMatched: Option<Price>,
     #[serde(skip_serializing_if = "Option::is_none")]
     priceReduced: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    sizeSettled: Option<Size>,
+    pub(crate) sizeSettled: Option<Size>,
     #[serde(skip_serializing_if = "Option::is_none")]
     profit: Option<Size>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1535,12 +2372,12 @@ pub struct ItemDescription {
     #[serde(skip_serializing_if = "Option::is_none")]
     runnerDesc: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    numberOfWinners: Option<i32>,
+    pub(crate) numberOfWinners: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    eachWayDivisor: Option<f64>,
+    pub(crate) eachWayDivisor: Option<f64>,
 }
 /// This object contains the unique identifier for a runner
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RunnerId {
     marketId: MarketId,
     selectionId: SelectionId,
@@ -1551,10 +2388,10 @@ pub struct RunnerId {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PlaceInstruction {
     orderType: OrderType,
-    selectionId: SelectionId,
+    pub(crate) selectionId: SelectionId,
     #[serde(skip_serializing_if = "Option::is_none")]
     handicap: Option<Handicap>,
-    side: Side,
+    pub(crate) side: Side,
     #[serde(skip_serializing_if = "Option::is_none")]
     limitOrder: Option<LimitOrder>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1568,9 +2405,9 @@ pub struct PlaceInstruction {
 pub struct PlaceExecutionReport {
     #[serde(skip_serializing_if = "Option::is_none")]
     customerRef: Option<String>,
-    status: String,
+    status: ExecutionReportStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
-    errorCode: Option<String>,
+    errorCode: Option<ExecutionReportErrorCode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     marketId: Option<MarketId>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1604,23 +2441,179 @@ pub struct LimitOnCloseOrder {
 pub struct MarketOnCloseOrder {
     liability: Size,
 }
+
+/// Betfair's length limit on `PlaceInstruction::customerOrderRef`.
+const CUSTOMER_ORDER_REF_MAX_LEN: usize = 32;
+/// Betfair's length limit on `placeOrdersRequest::customerStrategyRef`.
+const CUSTOMER_STRATEGY_REF_MAX_LEN: usize = 15;
+
+/// The order body of a [`PlaceInstruction`], carrying exactly one of
+/// `limitOrder`/`limitOnCloseOrder`/`marketOnCloseOrder` so that it and
+/// `orderType` can never disagree, unlike the three independently-optional
+/// fields on the underlying struct.
+#[derive(Debug)]
+pub enum OrderBody {
+    Limit(LimitOrder),
+    LimitOnClose(LimitOnCloseOrder),
+    MarketOnClose(MarketOnCloseOrder),
+}
+
+impl OrderBody {
+    fn order_type(&self) -> OrderType {
+        match self {
+            OrderBody::Limit(_) => OrderType::LIMIT,
+            OrderBody::LimitOnClose(_) => OrderType::LIMIT_ON_CLOSE,
+            OrderBody::MarketOnClose(_) => OrderType::MARKET_ON_CLOSE,
+        }
+    }
+}
+
+/// Builder for [`LimitOrder`]. `minFillSize` only makes sense once
+/// `timeInForce` (currently only `FILL_OR_KILL`) is also set, and
+/// `betTargetType`/`betTargetSize` only make sense together, so
+/// [`bet_target`](Self::bet_target) sets both at once.
+pub struct LimitOrderBuilder {
+    req: LimitOrder,
+}
+
+impl LimitOrder {
+    pub fn builder(price: Price) -> LimitOrderBuilder {
+        LimitOrderBuilder {
+            req: LimitOrder {
+                size: None,
+                price,
+                persistenceType: None,
+                timeInForce: None,
+                minFillSize: None,
+                betTargetType: None,
+                betTargetSize: None,
+            },
+        }
+    }
+}
+
+impl LimitOrderBuilder {
+    pub fn size(mut self, size: Size) -> Self {
+        self.req.size = Some(size);
+        self
+    }
+    pub fn persistence_type(mut self, persistence_type: PersistenceType) -> Self {
+        self.req.persistenceType = Some(persistence_type);
+        self
+    }
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.req.timeInForce = Some(time_in_force);
+        self
+    }
+    pub fn min_fill_size(mut self, min_fill_size: Size) -> Self {
+        self.req.minFillSize = Some(min_fill_size);
+        self
+    }
+    pub fn bet_target(mut self, bet_target_type: BetTargetType, bet_target_size: Size) -> Self {
+        self.req.betTargetType = Some(bet_target_type);
+        self.req.betTargetSize = Some(bet_target_size);
+        self
+    }
+
+    /// Validates that `minFillSize` was not set without `timeInForce`, and
+    /// assembles the order.
+    pub fn build(self) -> std::result::Result<LimitOrder, AnyError> {
+        if self.req.minFillSize.is_some() && self.req.timeInForce.is_none() {
+            return Err(AnyError::InvalidPlaceInstruction(
+                "minFillSize requires timeInForce to also be set".to_owned(),
+            ));
+        }
+        Ok(self.req)
+    }
+}
+
+/// Builder for [`PlaceInstruction`]. Taking the order body as a single
+/// [`OrderBody`] (rather than three independently-optional fields) makes an
+/// `orderType`/order-body mismatch unrepresentable.
+pub struct PlaceInstructionBuilder {
+    selectionId: SelectionId,
+    handicap: Option<Handicap>,
+    side: Side,
+    order: OrderBody,
+    customerOrderRef: Option<String>,
+}
+
+impl PlaceInstruction {
+    pub fn builder(
+        selectionId: SelectionId,
+        side: Side,
+        order: OrderBody,
+    ) -> PlaceInstructionBuilder {
+        PlaceInstructionBuilder {
+            selectionId,
+            handicap: None,
+            side,
+            order,
+            customerOrderRef: None,
+        }
+    }
+}
+
+impl PlaceInstructionBuilder {
+    pub fn handicap(mut self, handicap: Handicap) -> Self {
+        self.handicap = Some(handicap);
+        self
+    }
+    pub fn customer_order_ref(mut self, customer_order_ref: String) -> Self {
+        self.customerOrderRef = Some(customer_order_ref);
+        self
+    }
+
+    /// Validates `customerOrderRef`'s length limit and assembles the
+    /// instruction, setting `orderType` and the `limitOrder`/
+    /// `limitOnCloseOrder`/`marketOnCloseOrder` field to match `order`.
+    pub fn build(self) -> std::result::Result<PlaceInstruction, AnyError> {
+        if let Some(customer_order_ref) = &self.customerOrderRef {
+            if customer_order_ref.len() > CUSTOMER_ORDER_REF_MAX_LEN {
+                return Err(AnyError::InvalidPlaceInstruction(format!(
+                    "customerOrderRef must be at most {} characters, got {}",
+                    CUSTOMER_ORDER_REF_MAX_LEN,
+                    customer_order_ref.len()
+                )));
+            }
+        }
+
+        let orderType = self.order.order_type();
+        let (limitOrder, limitOnCloseOrder, marketOnCloseOrder) = match self.order {
+            OrderBody::Limit(order) => (Some(order), None, None),
+            OrderBody::LimitOnClose(order) => (None, Some(order), None),
+            OrderBody::MarketOnClose(order) => (None, None, Some(order)),
+        };
+
+        Ok(PlaceInstruction {
+            orderType,
+            selectionId: self.selectionId,
+            handicap: self.handicap,
+            side: self.side,
+            limitOrder,
+            limitOnCloseOrder,
+            marketOnCloseOrder,
+            customerOrderRef: self.customerOrderRef,
+        })
+    }
+}
 /// Response to a PlaceInstruction
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PlaceInstructionReport {
-    status: String,
+    status: InstructionReportStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
-    errorCode: Option<String>,
+    errorCode: Option<InstructionReportErrorCode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     orderStatus: Option<OrderStatus>,
-    instruction: PlaceInstruction,
+    pub(crate) instruction: PlaceInstruction,
     #[serde(skip_serializing_if = "Option::is_none")]
     betId: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     placedDate: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    averagePriceMatched: Option<Price>,
+    pub(crate) averagePriceMatched: Option<Price>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    sizeMatched: Option<Size>,
+    pub(crate) sizeMatched: Option<Size>,
 }
 /// Instruction to fully or partially cancel an order (only applies to LIMIT orders)
 #[derive(Debug, Deserialize, Serialize)]
@@ -1633,9 +2626,9 @@ pub struct CancelInstruction {
 pub struct CancelExecutionReport {
     #[serde(skip_serializing_if = "Option::is_none")]
     customerRef: Option<String>,
-    status: String,
+    status: ExecutionReportStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
-    errorCode: Option<String>,
+    errorCode: Option<ExecutionReportErrorCode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     marketId: Option<MarketId>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1651,9 +2644,9 @@ pub struct ReplaceInstruction {
 pub struct ReplaceExecutionReport {
     #[serde(skip_serializing_if = "Option::is_none")]
     customerRef: Option<String>,
-    status: String,
+    status: ExecutionReportStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
-    errorCode: Option<String>,
+    errorCode: Option<ExecutionReportErrorCode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     marketId: Option<MarketId>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1661,9 +2654,9 @@ pub struct ReplaceExecutionReport {
 }
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ReplaceInstructionReport {
-    status: String,
+    status: InstructionReportStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
-    errorCode: Option<String>,
+    errorCode: Option<InstructionReportErrorCode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     cancelInstructionReport: Option<CancelInstructionReport>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1671,9 +2664,9 @@ pub struct ReplaceInstructionReport {
 }
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CancelInstructionReport {
-    status: String,
+    status: InstructionReportStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
-    errorCode: Option<String>,
+    errorCode: Option<InstructionReportErrorCode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     instruction: Option<CancelInstruction>,
     sizeCancelled: Size,
@@ -1689,9 +2682,9 @@ pub struct UpdateInstruction {
 pub struct UpdateExecutionReport {
     #[serde(skip_serializing_if = "Option::is_none")]
     customerRef: Option<String>,
-    status: String,
+    status: ExecutionReportStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
-    errorCode: Option<String>,
+    errorCode: Option<ExecutionReportErrorCode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     marketId: Option<MarketId>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1699,9 +2692,9 @@ pub struct UpdateExecutionReport {
 }
 #[derive(Debug, Deserialize, Serialize)]
 pub struct UpdateInstructionReport {
-    status: String,
+    status: InstructionReportStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
-    errorCode: Option<String>,
+    errorCode: Option<InstructionReportErrorCode>,
     instruction: UpdateInstruction,
 }
 /// Selection criteria of the returning price data
@@ -1740,17 +2733,51 @@ pub struct MarketProfitAndLoss {
     #[serde(skip_serializing_if = "Option::is_none")]
     profitAndLosses: Option<Vec<RunnerProfitAndLoss>>,
 }
+
+impl MarketProfitAndLoss {
+    /// Builds a `MarketProfitAndLoss` report of the same shape the API
+    /// returns, from figures computed locally (see
+    /// [`crate::market_position`]) rather than fetched from the server.
+    pub(crate) fn new(
+        marketId: Option<String>,
+        commissionApplied: Option<f64>,
+        profitAndLosses: Vec<RunnerProfitAndLoss>,
+    ) -> Self {
+        MarketProfitAndLoss {
+            marketId,
+            commissionApplied,
+            profitAndLosses: Some(profitAndLosses),
+        }
+    }
+}
+
 /// Profit and loss if selection is wins or loses
 #[derive(Debug, Deserialize, Serialize)]
 pub struct RunnerProfitAndLoss {
     #[serde(skip_serializing_if = "Option::is_none")]
     selectionId: Option<SelectionId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    ifWin: Option<f64>,
+    ifWin: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    ifLose: Option<f64>,
+    ifLose: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    ifPlace: Option<f64>,
+    ifPlace: Option<Amount>,
+}
+
+impl RunnerProfitAndLoss {
+    pub(crate) fn new(
+        selectionId: SelectionId,
+        ifWin: f64,
+        ifLose: f64,
+        ifPlace: Option<f64>,
+    ) -> Self {
+        RunnerProfitAndLoss {
+            selectionId: Some(selectionId),
+            ifWin: Some(amount_from_f64(ifWin)),
+            ifLose: Some(amount_from_f64(ifLose)),
+            ifPlace: ifPlace.map(amount_from_f64),
+        }
+    }
 }
 /// Description of the price ladder type and any related data.
 #[derive(Debug, Deserialize, Serialize)]
@@ -1806,9 +2833,539 @@ pub struct MarketGroupExposureLimit {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ExposureLimit {
     #[serde(skip_serializing_if = "Option::is_none")]
-    matched: Option<f64>,
+    matched: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    total: Option<f64>,
+    total: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     limitBreachAction: Option<LimitBreachAction>,
 }
+
+/// Non-blocking twins of the functions above, built on `reqwest`'s async
+/// `Client`/`RequestBuilder` so callers can fan out many in-flight requests
+/// (e.g. polling `listMarketBook` for hundreds of markets at once) without
+/// tying up a thread per request. Only compiled in when the `async` feature
+/// is enabled, so blocking-only users aren't forced to pull in a runtime.
+#[cfg(feature = "async")]
+pub mod nonblocking {
+    use super::*;
+    use reqwest::RequestBuilder as AsyncRequestBuilder;
+
+    pub async fn listEventTypes(
+        rb: AsyncRequestBuilder,
+        filter: MarketFilter,
+        locale: Option<String>,
+    ) -> Result<Vec<EventTypeResult>, AnyError> {
+        let req: listEventTypesRequest = listEventTypesRequest { filter, locale };
+        let rpc_request: RpcRequest<listEventTypesRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listEventTypes".to_owned(), req);
+        let resp: RpcResponse<Vec<EventTypeResult>> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listCompetitions(
+        rb: AsyncRequestBuilder,
+        filter: MarketFilter,
+        locale: Option<String>,
+    ) -> Result<Vec<CompetitionResult>, AnyError> {
+        let req: listCompetitionsRequest = listCompetitionsRequest { filter, locale };
+        let rpc_request: RpcRequest<listCompetitionsRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listCompetitions".to_owned(), req);
+        let resp: RpcResponse<Vec<CompetitionResult>> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listTimeRanges(
+        rb: AsyncRequestBuilder,
+        filter: MarketFilter,
+        granularity: TimeGranularity,
+    ) -> Result<Vec<TimeRangeResult>, AnyError> {
+        let req: listTimeRangesRequest = listTimeRangesRequest {
+            filter,
+            granularity,
+        };
+        let rpc_request: RpcRequest<listTimeRangesRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listTimeRanges".to_owned(), req);
+        let resp: RpcResponse<Vec<TimeRangeResult>> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listEvents(
+        rb: AsyncRequestBuilder,
+        filter: MarketFilter,
+        locale: Option<String>,
+    ) -> Result<Vec<EventResult>, AnyError> {
+        let req: listEventsRequest = listEventsRequest { filter, locale };
+        let rpc_request: RpcRequest<listEventsRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listEvents".to_owned(), req);
+        let resp: RpcResponse<Vec<EventResult>> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listMarketTypes(
+        rb: AsyncRequestBuilder,
+        filter: MarketFilter,
+        locale: Option<String>,
+    ) -> Result<Vec<MarketTypeResult>, AnyError> {
+        let req: listMarketTypesRequest = listMarketTypesRequest { filter, locale };
+        let rpc_request: RpcRequest<listMarketTypesRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listMarketTypes".to_owned(), req);
+        let resp: RpcResponse<Vec<MarketTypeResult>> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listCountries(
+        rb: AsyncRequestBuilder,
+        filter: MarketFilter,
+        locale: Option<String>,
+    ) -> Result<Vec<CountryCodeResult>, AnyError> {
+        let req: listCountriesRequest = listCountriesRequest { filter, locale };
+        let rpc_request: RpcRequest<listCountriesRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listCountries".to_owned(), req);
+        let resp: RpcResponse<Vec<CountryCodeResult>> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listVenues(
+        rb: AsyncRequestBuilder,
+        filter: MarketFilter,
+        locale: Option<String>,
+    ) -> Result<Vec<VenueResult>, AnyError> {
+        let req: listVenuesRequest = listVenuesRequest { filter, locale };
+        let rpc_request: RpcRequest<listVenuesRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listVenues".to_owned(), req);
+        let resp: RpcResponse<Vec<VenueResult>> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listMarketCatalogue(
+        rb: AsyncRequestBuilder,
+        filter: MarketFilter,
+        marketProjection: Option<Vec<MarketProjection>>,
+        sort: Option<MarketSort>,
+        maxResults: i32,
+        locale: Option<String>,
+    ) -> Result<Vec<MarketCatalogue>, AnyError> {
+        let req: listMarketCatalogueRequest = listMarketCatalogueRequest {
+            filter,
+            marketProjection,
+            sort,
+            maxResults,
+            locale,
+        };
+        let rpc_request: RpcRequest<listMarketCatalogueRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listMarketCatalogue".to_owned(), req);
+        let resp: RpcResponse<Vec<MarketCatalogue>> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listMarketBook(
+        rb: AsyncRequestBuilder,
+        marketIds: Vec<MarketId>,
+        priceProjection: Option<PriceProjection>,
+        orderProjection: Option<OrderProjection>,
+        matchProjection: Option<MatchProjection>,
+        includeOverallPosition: Option<bool>,
+        partitionMatchedByStrategyRef: Option<bool>,
+        customerStrategyRefs: Option<Vec<String>>,
+        currencyCode: Option<String>,
+        locale: Option<String>,
+        matchedSince: Option<DateTime<Utc>>,
+        betIds: Option<Vec<BetId>>,
+    ) -> Result<Vec<MarketBook>, AnyError> {
+        let req: listMarketBookRequest = listMarketBookRequest {
+            marketIds,
+            priceProjection,
+            orderProjection,
+            matchProjection,
+            includeOverallPosition,
+            partitionMatchedByStrategyRef,
+            customerStrategyRefs,
+            currencyCode,
+            locale,
+            matchedSince,
+            betIds,
+        };
+        let rpc_request: RpcRequest<listMarketBookRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listMarketBook".to_owned(), req);
+        let resp: RpcResponse<Vec<MarketBook>> = rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listRunnerBook(
+        rb: AsyncRequestBuilder,
+        marketId: MarketId,
+        selectionId: SelectionId,
+        handicap: Option<f64>,
+        priceProjection: Option<PriceProjection>,
+        orderProjection: Option<OrderProjection>,
+        matchProjection: Option<MatchProjection>,
+        includeOverallPosition: Option<bool>,
+        partitionMatchedByStrategyRef: Option<bool>,
+        customerStrategyRefs: Option<Vec<String>>,
+        currencyCode: Option<String>,
+        locale: Option<String>,
+        matchedSince: Option<DateTime<Utc>>,
+        betIds: Option<Vec<BetId>>,
+    ) -> Result<Vec<MarketBook>, AnyError> {
+        let req: listRunnerBookRequest = listRunnerBookRequest {
+            marketId,
+            selectionId,
+            handicap,
+            priceProjection,
+            orderProjection,
+            matchProjection,
+            includeOverallPosition,
+            partitionMatchedByStrategyRef,
+            customerStrategyRefs,
+            currencyCode,
+            locale,
+            matchedSince,
+            betIds,
+        };
+        let rpc_request: RpcRequest<listRunnerBookRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listRunnerBook".to_owned(), req);
+        let resp: RpcResponse<Vec<MarketBook>> = rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listCurrentOrders(
+        rb: AsyncRequestBuilder,
+        betIds: Option<Vec<BetId>>,
+        marketIds: Option<Vec<MarketId>>,
+        orderProjection: Option<OrderProjection>,
+        customerOrderRefs: Option<Vec<CustomerOrderRef>>,
+        customerStrategyRefs: Option<Vec<CustomerStrategyRef>>,
+        placedDateRange: Option<TimeRange>,
+        dateRange: Option<TimeRange>,
+        orderBy: Option<OrderBy>,
+        sortDir: Option<SortDir>,
+        fromRecord: Option<i32>,
+        recordCount: Option<i32>,
+    ) -> Result<CurrentOrderSummaryReport, AnyError> {
+        let req: listCurrentOrdersRequest = listCurrentOrdersRequest {
+            betIds,
+            marketIds,
+            orderProjection,
+            customerOrderRefs,
+            customerStrategyRefs,
+            placedDateRange,
+            dateRange,
+            orderBy,
+            sortDir,
+            fromRecord,
+            recordCount,
+        };
+        let rpc_request: RpcRequest<listCurrentOrdersRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listCurrentOrders".to_owned(), req);
+        let resp: RpcResponse<CurrentOrderSummaryReport> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listClearedOrders(
+        rb: AsyncRequestBuilder,
+        betStatus: BetStatus,
+        eventTypeIds: Option<Vec<EventTypeId>>,
+        eventIds: Option<Vec<EventId>>,
+        marketIds: Option<Vec<MarketId>>,
+        runnerIds: Option<Vec<RunnerId>>,
+        betIds: Option<Vec<BetId>>,
+        customerOrderRefs: Option<Vec<CustomerOrderRef>>,
+        customerStrategyRefs: Option<Vec<CustomerStrategyRef>>,
+        side: Option<Side>,
+        settledDateRange: Option<TimeRange>,
+        groupBy: Option<GroupBy>,
+        includeItemDescription: Option<bool>,
+        locale: Option<String>,
+        fromRecord: Option<i32>,
+        recordCount: Option<i32>,
+    ) -> Result<ClearedOrderSummaryReport, AnyError> {
+        let req: listClearedOrdersRequest = listClearedOrdersRequest {
+            betStatus,
+            eventTypeIds,
+            eventIds,
+            marketIds,
+            runnerIds,
+            betIds,
+            customerOrderRefs,
+            customerStrategyRefs,
+            side,
+            settledDateRange,
+            groupBy,
+            includeItemDescription,
+            locale,
+            fromRecord,
+            recordCount,
+        };
+        let rpc_request: RpcRequest<listClearedOrdersRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listClearedOrders".to_owned(), req);
+        let resp: RpcResponse<ClearedOrderSummaryReport> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn placeOrders(
+        rb: AsyncRequestBuilder,
+        marketId: MarketId,
+        instructions: Vec<PlaceInstruction>,
+        customerRef: Option<String>,
+        marketVersion: Option<MarketVersion>,
+        customerStrategyRef: Option<String>,
+        r#async: Option<bool>,
+    ) -> Result<PlaceExecutionReport, AnyError> {
+        for instruction in &instructions {
+            if let Some(limit_order) = &instruction.limitOrder {
+                if !limit_order.price.is_valid_ladder_tick() {
+                    return Err(AnyError::InvalidLadderPrice(limit_order.price));
+                }
+            }
+        }
+        if let Some(customer_strategy_ref) = &customerStrategyRef {
+            if customer_strategy_ref.len() > CUSTOMER_STRATEGY_REF_MAX_LEN {
+                return Err(AnyError::InvalidPlaceInstruction(format!(
+                    "customerStrategyRef must be at most {} characters, got {}",
+                    CUSTOMER_STRATEGY_REF_MAX_LEN,
+                    customer_strategy_ref.len()
+                )));
+            }
+        }
+
+        let req: placeOrdersRequest = placeOrdersRequest {
+            marketId,
+            instructions,
+            customerRef,
+            marketVersion,
+            customerStrategyRef,
+            r#async,
+        };
+        let rpc_request: RpcRequest<placeOrdersRequest> =
+            RpcRequest::new("SportsAPING/v1.0/placeOrders".to_owned(), req);
+        let resp: RpcResponse<PlaceExecutionReport> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn cancelOrders(
+        rb: AsyncRequestBuilder,
+        marketId: Option<MarketId>,
+        instructions: Option<Vec<CancelInstruction>>,
+        customerRef: Option<String>,
+    ) -> Result<CancelExecutionReport, AnyError> {
+        let req: cancelOrdersRequest = cancelOrdersRequest {
+            marketId,
+            instructions,
+            customerRef,
+        };
+        let rpc_request: RpcRequest<cancelOrdersRequest> =
+            RpcRequest::new("SportsAPING/v1.0/cancelOrders".to_owned(), req);
+        let resp: RpcResponse<CancelExecutionReport> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn replaceOrders(
+        rb: AsyncRequestBuilder,
+        marketId: MarketId,
+        instructions: Vec<ReplaceInstruction>,
+        customerRef: Option<String>,
+        marketVersion: Option<MarketVersion>,
+        r#async: Option<bool>,
+    ) -> Result<ReplaceExecutionReport, AnyError> {
+        for instruction in &instructions {
+            if !instruction.newPrice.is_valid_ladder_tick() {
+                return Err(AnyError::InvalidLadderPrice(instruction.newPrice));
+            }
+        }
+
+        let req: replaceOrdersRequest = replaceOrdersRequest {
+            marketId,
+            instructions,
+            customerRef,
+            marketVersion,
+            r#async,
+        };
+        let rpc_request: RpcRequest<replaceOrdersRequest> =
+            RpcRequest::new("SportsAPING/v1.0/replaceOrders".to_owned(), req);
+        let resp: RpcResponse<ReplaceExecutionReport> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn updateOrders(
+        rb: AsyncRequestBuilder,
+        marketId: MarketId,
+        instructions: Vec<UpdateInstruction>,
+        customerRef: Option<String>,
+    ) -> Result<UpdateExecutionReport, AnyError> {
+        let req: updateOrdersRequest = updateOrdersRequest {
+            marketId,
+            instructions,
+            customerRef,
+        };
+        let rpc_request: RpcRequest<updateOrdersRequest> =
+            RpcRequest::new("SportsAPING/v1.0/updateOrders".to_owned(), req);
+        let resp: RpcResponse<UpdateExecutionReport> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listMarketProfitAndLoss(
+        rb: AsyncRequestBuilder,
+        marketIds: Vec<MarketId>,
+        includeSettledBets: Option<bool>,
+        includeBspBets: Option<bool>,
+        netOfCommission: Option<bool>,
+    ) -> Result<Vec<MarketProfitAndLoss>, AnyError> {
+        let req: listMarketProfitAndLossRequest = listMarketProfitAndLossRequest {
+            marketIds,
+            includeSettledBets,
+            includeBspBets,
+            netOfCommission,
+        };
+        let rpc_request: RpcRequest<listMarketProfitAndLossRequest> =
+            RpcRequest::new("SportsAPING/v1.0/listMarketProfitAndLoss".to_owned(), req);
+        let resp: RpcResponse<Vec<MarketProfitAndLoss>> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn setDefaultExposureLimitForMarketGroups(
+        rb: AsyncRequestBuilder,
+        marketGroupType: MarketGroupType,
+        limit: ExposureLimit,
+    ) -> Result<String, AnyError> {
+        let req: setDefaultExposureLimitForMarketGroupsRequest =
+            setDefaultExposureLimitForMarketGroupsRequest {
+                marketGroupType,
+                limit,
+            };
+        let rpc_request: RpcRequest<setDefaultExposureLimitForMarketGroupsRequest> =
+            RpcRequest::new(
+                "SportsAPING/v1.0/setDefaultExposureLimitForMarketGroups".to_owned(),
+                req,
+            );
+        let resp: RpcResponse<String> = rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn setExposureLimitForMarketGroup(
+        rb: AsyncRequestBuilder,
+        marketGroup: MarketGroup,
+        limit: ExposureLimit,
+    ) -> Result<String, AnyError> {
+        let req: setExposureLimitForMarketGroupRequest =
+            setExposureLimitForMarketGroupRequest { marketGroup, limit };
+        let rpc_request: RpcRequest<setExposureLimitForMarketGroupRequest> = RpcRequest::new(
+            "SportsAPING/v1.0/setExposureLimitForMarketGroup".to_owned(),
+            req,
+        );
+        let resp: RpcResponse<String> = rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn removeDefaultExposureLimitForMarketGroups(
+        rb: AsyncRequestBuilder,
+        marketGroupType: MarketGroupType,
+    ) -> Result<String, AnyError> {
+        let req: removeDefaultExposureLimitForMarketGroupsRequest =
+            removeDefaultExposureLimitForMarketGroupsRequest { marketGroupType };
+        let rpc_request: RpcRequest<removeDefaultExposureLimitForMarketGroupsRequest> =
+            RpcRequest::new(
+                "SportsAPING/v1.0/removeDefaultExposureLimitForMarketGroups".to_owned(),
+                req,
+            );
+        let resp: RpcResponse<String> = rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn removeExposureLimitForMarketGroup(
+        rb: AsyncRequestBuilder,
+        marketGroup: MarketGroup,
+    ) -> Result<String, AnyError> {
+        let req: removeExposureLimitForMarketGroupRequest =
+            removeExposureLimitForMarketGroupRequest { marketGroup };
+        let rpc_request: RpcRequest<removeExposureLimitForMarketGroupRequest> = RpcRequest::new(
+            "SportsAPING/v1.0/removeExposureLimitForMarketGroup".to_owned(),
+            req,
+        );
+        let resp: RpcResponse<String> = rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn listExposureLimitsForMarketGroups(
+        rb: AsyncRequestBuilder,
+        marketGroupTypeFilter: Option<MarketGroupType>,
+        marketGroupFilter: Option<Vec<MarketGroup>>,
+    ) -> Result<Vec<ExposureLimitsForMarketGroups>, AnyError> {
+        let req: listExposureLimitsForMarketGroupsRequest =
+            listExposureLimitsForMarketGroupsRequest {
+                marketGroupTypeFilter,
+                marketGroupFilter,
+            };
+        let rpc_request: RpcRequest<listExposureLimitsForMarketGroupsRequest> = RpcRequest::new(
+            "SportsAPING/v1.0/listExposureLimitsForMarketGroups".to_owned(),
+            req,
+        );
+        let resp: RpcResponse<Vec<ExposureLimitsForMarketGroups>> =
+            rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn unblockMarketGroup(
+        rb: AsyncRequestBuilder,
+        marketGroup: MarketGroup,
+    ) -> Result<String, AnyError> {
+        let req: unblockMarketGroupRequest = unblockMarketGroupRequest { marketGroup };
+        let rpc_request: RpcRequest<unblockMarketGroupRequest> =
+            RpcRequest::new("SportsAPING/v1.0/unblockMarketGroup".to_owned(), req);
+        let resp: RpcResponse<String> = rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn getExposureReuseEnabledEvents(
+        rb: AsyncRequestBuilder,
+    ) -> Result<Vec<i64>, AnyError> {
+        let rpc_request: RpcRequest<()> = RpcRequest::new(
+            "SportsAPING/v1.0/getExposureReuseEnabledEvents".to_owned(),
+            (),
+        );
+        let resp: RpcResponse<Vec<i64>> = rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn addExposureReuseEnabledEvents(
+        rb: AsyncRequestBuilder,
+        eventIds: Vec<i64>,
+    ) -> Result<String, AnyError> {
+        let req: addExposureReuseEnabledEventsRequest =
+            addExposureReuseEnabledEventsRequest { eventIds };
+        let rpc_request: RpcRequest<addExposureReuseEnabledEventsRequest> = RpcRequest::new(
+            "SportsAPING/v1.0/addExposureReuseEnabledEvents".to_owned(),
+            req,
+        );
+        let resp: RpcResponse<String> = rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+
+    pub async fn removeExposureReuseEnabledEvents(
+        rb: AsyncRequestBuilder,
+        eventIds: Vec<i64>,
+    ) -> Result<String, AnyError> {
+        let req: removeExposureReuseEnabledEventsRequest =
+            removeExposureReuseEnabledEventsRequest { eventIds };
+        let rpc_request: RpcRequest<removeExposureReuseEnabledEventsRequest> = RpcRequest::new(
+            "SportsAPING/v1.0/removeExposureReuseEnabledEvents".to_owned(),
+            req,
+        );
+        let resp: RpcResponse<String> = rb.json(&rpc_request).send().await?.json().await?;
+        Ok(resp.into_inner()?)
+    }
+}
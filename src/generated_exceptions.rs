@@ -0,0 +1,47 @@
+// SPDX-Copyright: Copyright (c) 2019 Daniel Edgecumbe (esotericnonsense)
+// SPDX-License-Identifier: AGPL-3.0-only
+//
+// This file is part of botfair.  botfair is free software: you can
+// redistribute it and/or modify it under the terms of the GNU Affero General
+// Public License as published by the Free Software Foundation, either version
+// 3 of the License, or (at your option) any later version.
+//
+// botfair is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE.  See the GNU Affero General Public License
+// for more details.
+//
+// You should have received a copy of the GNU Affero General Public License
+// along with botfair.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The `errorCode` values carried by a Betfair `APINGException`, as returned
+//! in the `error.data.APINGException.errorCode` field of a JSON-RPC fault
+//! response. See [`crate::result::Error::APINGException`].
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[allow(non_camel_case_types)]
+pub enum errorCode {
+    TOO_MUCH_DATA,
+    INVALID_INPUT_DATA,
+    INVALID_SESSION_INFORMATION,
+    NO_APP_KEY,
+    NO_SESSION,
+    INVALID_APP_KEY,
+    TOO_MANY_REQUESTS,
+    SERVICE_BUSY,
+    TIMEOUT_ERROR,
+    REQUEST_SIZE_EXCEEDS_LIMIT,
+    ACCESS_DENIED,
+    INVALID_CLIENT_REF,
+    UNEXPECTED_ERROR,
+    INVALID_STATUS,
+    MARKET_SUSPENDED,
+    VENDOR_ACCESS_DENIED,
+    /// Any errorCode the crate doesn't yet know the name of. Betfair has
+    /// historically added new codes without a major version bump, so this
+    /// keeps deserialization from hard-failing on one we haven't seen yet.
+    #[serde(other)]
+    Unknown,
+}
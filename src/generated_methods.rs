@@ -0,0 +1,133 @@
+// Wires each `*Request` type in `generated_api` to its JSON-RPC method name
+// and response type via `RpcCall`, so `Session::call_rpc` can dispatch any
+// SportsAPING operation generically. Kept separate from `generated_api`
+// itself (marked "This file is generated. Any manual edits will be
+// overwritten.") since these impls aren't produced by that generator.
+
+use crate::generated_api::*;
+use crate::json_rpc::RpcCall;
+
+impl RpcCall for listEventTypesRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listEventTypes";
+    type Response = Vec<EventTypeResult>;
+}
+
+impl RpcCall for listCompetitionsRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listCompetitions";
+    type Response = Vec<CompetitionResult>;
+}
+
+impl RpcCall for listTimeRangesRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listTimeRanges";
+    type Response = Vec<TimeRangeResult>;
+}
+
+impl RpcCall for listEventsRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listEvents";
+    type Response = Vec<EventResult>;
+}
+
+impl RpcCall for listMarketTypesRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listMarketTypes";
+    type Response = Vec<MarketTypeResult>;
+}
+
+impl RpcCall for listCountriesRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listCountries";
+    type Response = Vec<CountryCodeResult>;
+}
+
+impl RpcCall for listVenuesRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listVenues";
+    type Response = Vec<VenueResult>;
+}
+
+impl RpcCall for listMarketCatalogueRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listMarketCatalogue";
+    type Response = Vec<MarketCatalogue>;
+}
+
+impl RpcCall for listMarketBookRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listMarketBook";
+    type Response = Vec<MarketBook>;
+}
+
+impl RpcCall for listRunnerBookRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listRunnerBook";
+    type Response = Vec<MarketBook>;
+}
+
+impl RpcCall for listCurrentOrdersRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listCurrentOrders";
+    type Response = CurrentOrderSummaryReport;
+}
+
+impl RpcCall for listClearedOrdersRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listClearedOrders";
+    type Response = ClearedOrderSummaryReport;
+}
+
+impl RpcCall for placeOrdersRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/placeOrders";
+    type Response = PlaceExecutionReport;
+}
+
+impl RpcCall for cancelOrdersRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/cancelOrders";
+    type Response = CancelExecutionReport;
+}
+
+impl RpcCall for replaceOrdersRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/replaceOrders";
+    type Response = ReplaceExecutionReport;
+}
+
+impl RpcCall for updateOrdersRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/updateOrders";
+    type Response = UpdateExecutionReport;
+}
+
+impl RpcCall for listMarketProfitAndLossRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listMarketProfitAndLoss";
+    type Response = Vec<MarketProfitAndLoss>;
+}
+
+impl RpcCall for setDefaultExposureLimitForMarketGroupsRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/setDefaultExposureLimitForMarketGroups";
+    type Response = String;
+}
+
+impl RpcCall for setExposureLimitForMarketGroupRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/setExposureLimitForMarketGroup";
+    type Response = String;
+}
+
+impl RpcCall for removeDefaultExposureLimitForMarketGroupsRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/removeDefaultExposureLimitForMarketGroups";
+    type Response = String;
+}
+
+impl RpcCall for removeExposureLimitForMarketGroupRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/removeExposureLimitForMarketGroup";
+    type Response = String;
+}
+
+impl RpcCall for listExposureLimitsForMarketGroupsRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/listExposureLimitsForMarketGroups";
+    type Response = Vec<ExposureLimitsForMarketGroups>;
+}
+
+impl RpcCall for unblockMarketGroupRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/unblockMarketGroup";
+    type Response = String;
+}
+
+impl RpcCall for addExposureReuseEnabledEventsRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/addExposureReuseEnabledEvents";
+    type Response = String;
+}
+
+impl RpcCall for removeExposureReuseEnabledEventsRequest {
+    const METHOD: &'static str = "SportsAPING/v1.0/removeExposureReuseEnabledEvents";
+    type Response = String;
+}
@@ -15,7 +15,7 @@
 // along with botfair.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::generated_exceptions::errorCode;
-use crate::result::{Error, Result};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize)]
@@ -38,10 +38,24 @@ impl<T> RpcRequest<T> {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct ApingExceptionData {
+    #[serde(rename = "APINGException")]
+    aping_exception: ApingExceptionBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApingExceptionBody {
+    errorCode: errorCode,
+    requestUUID: Option<String>,
+    errorDetails: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RpcError {
     code: i32, // TODO are these ever meaningful?
-    message: errorCode,
+    message: String,
+    data: Option<ApingExceptionData>,
 }
 
 #[derive(Deserialize)]
@@ -52,17 +66,67 @@ pub struct RpcResponse<T> {
     id: String,
 }
 
+/// A decoded Betfair `APINGException`, as nested under the `data` field of
+/// a JSON-RPC error response. Kept crate-agnostic (no dependency on
+/// `crate::result` or any particular binary's error type) so both the
+/// library's [`crate::result::Error::APINGException`] and a binary's own
+/// error type can fold it in.
+#[derive(Debug, Clone)]
+pub struct BetfairException {
+    pub error_code: errorCode,
+    pub request_uuid: Option<String>,
+    pub error_details: Option<String>,
+}
+
+/// The outcome of unwrapping an [`RpcResponse`] that didn't carry a
+/// `result`: either a structured Betfair fault, a JSON-RPC-level fault with
+/// no nested `APINGException`, or a response with neither `result` nor
+/// `error` set.
+#[derive(Debug)]
+pub enum RpcFault {
+    /// Betfair returned a structured `APINGException`, e.g.
+    /// `INVALID_SESSION_INFORMATION`.
+    Aping(BetfairException),
+    /// A JSON-RPC-level fault with no nested `APINGException`, e.g. a
+    /// malformed request. Carries the raw `error.message`.
+    Raw(String),
+    /// The response had neither a `result` nor an `error` field.
+    Empty,
+}
+
+/// Associates a generated `*Request` type with the JSON-RPC method name and
+/// response type it was generated alongside, so a single generic dispatcher
+/// (`Session::call_rpc` in the binary) can
+/// send any operation without a hand-written `method` string and response
+/// type per call site.
+pub trait RpcCall: Serialize {
+    /// The full method name Betfair expects in the JSON-RPC request, e.g.
+    /// `"SportsAPING/v1.0/listMarketBook"`.
+    const METHOD: &'static str;
+    type Response: DeserializeOwned;
+}
+
 impl<T> RpcResponse<T> {
     // TODO: rustic way to perform this?
-    pub fn into_inner(self) -> Result<T> {
+    pub fn into_inner(self) -> std::result::Result<T, RpcFault> {
         let _ = self.jsonrpc; // This should always be "2.0".
         let _ = self.id; // We could check this against the request.
         match (self.error, self.result) {
             (Some(rpc_error), _) => {
-                Err(Error::APINGException(rpc_error.message))
+                let _ = rpc_error.code;
+                match rpc_error.data {
+                    Some(data) => Err(RpcFault::Aping(BetfairException {
+                        error_code: data.aping_exception.errorCode,
+                        request_uuid: data.aping_exception.requestUUID,
+                        error_details: data.aping_exception.errorDetails,
+                    })),
+                    // A JSON-RPC-level fault with no nested APINGException,
+                    // e.g. a malformed request; surface the raw message.
+                    None => Err(RpcFault::Raw(rpc_error.message)),
+                }
             }
             (None, Some(result)) => Ok(result),
-            (None, None) => Err(Error::JSONRPCError),
+            (None, None) => Err(RpcFault::Empty),
         }
     }
 }
@@ -0,0 +1,218 @@
+// SPDX-Copyright: Copyright (c) 2019 Daniel Edgecumbe (esotericnonsense)
+// SPDX-License-Identifier: AGPL-3.0-only
+//
+// This file is part of botfair.  botfair is free software: you can
+// redistribute it and/or modify it under the terms of the GNU Affero General
+// Public License as published by the Free Software Foundation, either version
+// 3 of the License, or (at your option) any later version.
+//
+// botfair is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE.  See the GNU Affero General Public License
+// for more details.
+//
+// You should have received a copy of the GNU Affero General Public License
+// along with botfair.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Betfair's fixed odds ladder.
+//!
+//! Betfair only accepts prices that sit on its discrete ladder of ~350
+//! rungs; anything else is rejected server-side with `INVALID_ODDS`. This
+//! module precomputes the ladder once and exposes helpers to validate a
+//! price or snap it to the nearest (or next, in a given direction) valid
+//! rung, so that callers can catch the mistake locally before ever
+//! submitting an order.
+
+use std::sync::OnceLock;
+
+/// The direction to round towards when a price doesn't sit on a valid rung.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundDirection {
+    /// Round to the nearest rung at or below the given price (shorter odds).
+    Down,
+    /// Round to the nearest rung at or above the given price (longer odds).
+    Up,
+    /// Round to whichever rung is numerically closest.
+    Nearest,
+}
+
+/// `(lower bound inclusive, upper bound exclusive, increment)` in pence of
+/// odds, i.e. price * 100, so that the table and all arithmetic on it stays
+/// in integers and is immune to floating-point drift. Each upper bound is the
+/// next band's lower bound; the ladder's top rung (1000.00) is added
+/// separately.
+const BANDS: &[(i64, i64, i64)] = &[
+    (101, 200, 1),
+    (200, 300, 2),
+    (300, 400, 5),
+    (400, 600, 10),
+    (600, 1000, 20),
+    (1000, 2000, 50),
+    (2000, 3000, 100),
+    (3000, 5000, 200),
+    (5000, 10000, 500),
+    (10000, 100000, 1000),
+];
+
+fn rungs() -> &'static Vec<i64> {
+    static RUNGS: OnceLock<Vec<i64>> = OnceLock::new();
+    RUNGS.get_or_init(|| {
+        let mut rungs = Vec::with_capacity(350);
+        for &(lower, upper, step) in BANDS {
+            let mut p = lower;
+            while p < upper {
+                rungs.push(p);
+                p += step;
+            }
+        }
+        rungs.push(BANDS.last().unwrap().1);
+        rungs
+    })
+}
+
+fn to_pence(price: f64) -> i64 {
+    (price * 100.0).round() as i64
+}
+
+fn from_pence(pence: i64) -> f64 {
+    pence as f64 / 100.0
+}
+
+fn clamp_pence(pence: i64) -> i64 {
+    let rungs = rungs();
+    pence.clamp(rungs[0], rungs[rungs.len() - 1])
+}
+
+/// Returns `true` if `price` sits on a valid Betfair ladder rung. `price` is
+/// first rounded to the nearest hundredth, so anything within half a pence of
+/// a rung is accepted.
+pub fn is_valid(price: f64) -> bool {
+    let pence = to_pence(price);
+    rungs().binary_search(&pence).is_ok()
+}
+
+/// Snaps `price` to the nearest valid rung in the given `direction`,
+/// clamping to the ladder's bounds (1.01 to 1000.0) if `price` falls
+/// outside them.
+pub fn round_to_tick(price: f64, direction: RoundDirection) -> f64 {
+    let rungs = rungs();
+    let pence = clamp_pence(to_pence(price));
+
+    match rungs.binary_search(&pence) {
+        Ok(i) => from_pence(rungs[i]),
+        Err(i) => {
+            // `i` is the insertion point: rungs[i - 1] < pence < rungs[i].
+            let lower = rungs[i - 1];
+            let upper = rungs[i];
+            let chosen = match direction {
+                RoundDirection::Down => lower,
+                RoundDirection::Up => upper,
+                RoundDirection::Nearest => {
+                    if pence - lower <= upper - pence {
+                        lower
+                    } else {
+                        upper
+                    }
+                }
+            };
+            from_pence(chosen)
+        }
+    }
+}
+
+/// Returns the rung `n` steps above `price` (rounding down onto the ladder
+/// first, if `price` doesn't already sit on a rung).
+pub fn tick_up(price: f64, n: usize) -> f64 {
+    let rungs = rungs();
+    let pence = clamp_pence(to_pence(price));
+    let i = match rungs.binary_search(&pence) {
+        Ok(i) => i,
+        Err(i) => i - 1,
+    };
+    let j = (i + n).min(rungs.len() - 1);
+    from_pence(rungs[j])
+}
+
+/// Returns the rung `n` steps below `price` (rounding up onto the ladder
+/// first, if `price` doesn't already sit on a rung).
+pub fn tick_down(price: f64, n: usize) -> f64 {
+    let rungs = rungs();
+    let pence = clamp_pence(to_pence(price));
+    let i = match rungs.binary_search(&pence) {
+        Ok(i) => i,
+        Err(i) => i,
+    };
+    let j = i.saturating_sub(n);
+    from_pence(rungs[j])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_valid_accepts_rungs_from_every_band() {
+        assert!(is_valid(1.01));
+        assert!(is_valid(1.99));
+        assert!(is_valid(2.0));
+        assert!(is_valid(2.02));
+        assert!(is_valid(4.9));
+        assert!(is_valid(1000.0));
+    }
+
+    #[test]
+    fn is_valid_rejects_prices_off_the_ladder() {
+        // The 2-3 band steps by 0.02, so odd pence values like 2.01/2.99
+        // don't sit on a rung even though they're within the ladder's range.
+        assert!(!is_valid(2.01));
+        assert!(!is_valid(2.99));
+        // The 4-6 band steps by 0.10.
+        assert!(!is_valid(4.95));
+    }
+
+    #[test]
+    fn round_to_tick_rounds_to_the_enclosing_rungs_across_a_band_boundary() {
+        // 2.99 sits between the 2-3 band's last rung (2.98) and the 3-4
+        // band's first rung (3.00).
+        assert_eq!(round_to_tick(2.99, RoundDirection::Down), 2.98);
+        assert_eq!(round_to_tick(2.99, RoundDirection::Up), 3.00);
+    }
+
+    #[test]
+    fn round_to_tick_down_and_up_bracket_an_off_ladder_price() {
+        assert_eq!(round_to_tick(4.93, RoundDirection::Down), 4.90);
+        assert_eq!(round_to_tick(4.93, RoundDirection::Up), 5.00);
+    }
+
+    #[test]
+    fn round_to_tick_nearest_picks_the_closer_rung() {
+        assert_eq!(round_to_tick(4.91, RoundDirection::Nearest), 4.90);
+        assert_eq!(round_to_tick(4.99, RoundDirection::Nearest), 5.00);
+    }
+
+    #[test]
+    fn round_to_tick_on_a_valid_rung_is_a_no_op() {
+        assert_eq!(round_to_tick(2.0, RoundDirection::Down), 2.0);
+        assert_eq!(round_to_tick(2.0, RoundDirection::Up), 2.0);
+    }
+
+    #[test]
+    fn round_to_tick_clamps_outside_the_ladder_bounds() {
+        assert_eq!(round_to_tick(0.5, RoundDirection::Down), 1.01);
+        assert_eq!(round_to_tick(5000.0, RoundDirection::Up), 1000.0);
+    }
+
+    #[test]
+    fn tick_up_and_tick_down_move_by_n_rungs() {
+        assert_eq!(tick_up(1.99, 1), 2.00);
+        assert_eq!(tick_up(2.0, 2), 2.04);
+        assert_eq!(tick_down(2.00, 1), 1.99);
+        assert_eq!(tick_down(2.04, 2), 2.00);
+    }
+
+    #[test]
+    fn tick_up_and_tick_down_saturate_at_the_ladder_bounds() {
+        assert_eq!(tick_up(1000.0, 5), 1000.0);
+        assert_eq!(tick_down(1.01, 5), 1.01);
+    }
+}
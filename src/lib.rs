@@ -38,16 +38,18 @@
 //! <botfair@esotericnonsense.com>.
 //!
 //! ## Example
-//! Note that `botfair` requires your certificate to be in `pfx` format.
-//! In order to achieve this given a key and crt file, you can use the
-//! following openssl command:
+//! `BFCredentials::from_pem` accepts the `key`/`crt` files Betfair issues
+//! directly. If you'd rather convert them to a PKCS#12 bundle yourself (or
+//! already have one), use `BFCredentials::from_pkcs12`/`BFCredentials::new`
+//! instead:
 //!
 //! ```text
 //! openssl pkcs12 -export -out client.pfx \
 //!     -inkey client.key -in client.crt
 //! ```
 //!
-//! `botfair` assumes no password protection for the `pfx` file.
+//! `BFCredentials::new` assumes no password protection for the `pfx` file;
+//! pass one through `BFCredentials::from_pkcs12` otherwise.
 //!
 //! ```
 //! use botfair::generated_types::{MarketBook, MarketCatalogue};
@@ -130,8 +132,10 @@ pub mod generated_exceptions;
 mod generated_methods;
 mod generated_requests;
 pub mod generated_types;
+pub mod ladder;
 mod json_rpc;
 pub mod result;
+pub mod streaming;
 
 pub mod prelude {
     pub use crate::client::BFClient;
@@ -18,29 +18,96 @@
 #[macro_use]
 extern crate log;
 
-use reqwest::{Client, Identity};
 use serde::{Deserialize, Serialize};
-use std::fs;
 
+mod account_api;
+mod cert;
+mod config;
 mod generated_api;
+mod generated_exceptions;
+mod generated_methods;
 mod json_rpc;
+mod ladder;
+mod market_position;
+mod session;
+mod streaming;
 
-const CERTLOGIN_URI: &str =
-    "https://identitysso-cert.betfair.com/api/certlogin";
-const JSONRPC_URI: &str =
-    "https://api.betfair.com/exchange/betting/json-rpc/v1";
-const PFXFILE: &str = "/home/esotericnonsense/betfair/identity.pfx";
-const APPKEYFILE: &str = "/home/esotericnonsense/betfair/betfair-app-key";
-const USERFILE: &str = "/home/esotericnonsense/betfair/betfair-user";
-const PASSFILE: &str = "/home/esotericnonsense/betfair/betfair-pass";
+use config::BotfairConfig;
 
 #[derive(Debug)]
 enum AnyError {
+    /// A required piece of configuration (credential, app key, identity)
+    /// was never supplied to [`config::ClientBuilder`].
+    ConfigMissing(String),
     Io(std::io::Error),
     Reqwest(reqwest::Error),
+    /// A `Price` that does not sit on Betfair's fixed odds ladder was about
+    /// to be submitted in a `LimitOrder` or `ReplaceInstruction`.
+    InvalidLadderPrice(generated_api::Price),
+    /// A `PlaceInstruction`/`LimitOrder` built via their validating builders
+    /// (or a `customerStrategyRef` about to be submitted with `placeOrders`)
+    /// failed validation, e.g. a reference exceeding Betfair's length limit.
+    InvalidPlaceInstruction(String),
+    /// TLS handshake/record-layer error on the Exchange Stream socket.
+    Tls(native_tls::Error),
+    /// Malformed JSON from the Exchange Stream socket.
+    Json(serde_json::Error),
+    /// The Exchange Stream TLS handshake did not complete.
+    StreamHandshakeTimeout,
+    /// The Exchange Stream server closed the connection in response to a
+    /// failed `authentication`/`marketSubscription` request.
+    StreamAuthenticationFailed(String),
+    /// A `streaming::StreamClient` call was made without a live connection.
+    StreamNotConnected,
+    /// A JSON-RPC call completed but Betfair returned an `APINGException`
+    /// fault instead of a result, e.g. `INVALID_SESSION_INFORMATION` once
+    /// the session token has expired.
+    BetfairException(json_rpc::BetfairException),
+    /// The JSON-RPC response had neither a `result` nor an `error` field.
+    JSONRPCError,
+    /// Failure generating the non-interactive login identity (key
+    /// generation, certificate self-signing, or PKCS#12 packaging).
+    Openssl(openssl::error::ErrorStack),
+    /// `Session::keep_alive` did not report `SUCCESS`.
+    SessionKeepAliveFailure(session::KeepAliveError),
+    /// `Session::logout` did not report `SUCCESS`.
+    SessionLogoutFailure(String),
     Other,
 }
 
+impl From<json_rpc::RpcFault> for AnyError {
+    fn from(f: json_rpc::RpcFault) -> Self {
+        match f {
+            json_rpc::RpcFault::Aping(e) => AnyError::BetfairException(e),
+            // A JSON-RPC-level fault with no nested APINGException, e.g. a
+            // malformed request; surface the raw message under `Unknown`.
+            json_rpc::RpcFault::Raw(message) => {
+                AnyError::BetfairException(json_rpc::BetfairException {
+                    error_code: generated_exceptions::errorCode::Unknown,
+                    request_uuid: None,
+                    error_details: Some(message),
+                })
+            }
+            json_rpc::RpcFault::Empty => AnyError::JSONRPCError,
+        }
+    }
+}
+
+impl From<streaming::StreamError> for AnyError {
+    fn from(e: streaming::StreamError) -> Self {
+        match e {
+            streaming::StreamError::Io(e) => AnyError::Io(e),
+            streaming::StreamError::Tls(e) => AnyError::Tls(e),
+            streaming::StreamError::Json(e) => AnyError::Json(e),
+            streaming::StreamError::HandshakeTimeout => AnyError::StreamHandshakeTimeout,
+            streaming::StreamError::NotConnected => AnyError::StreamNotConnected,
+            streaming::StreamError::AuthenticationFailed(m) => {
+                AnyError::StreamAuthenticationFailed(m)
+            }
+        }
+    }
+}
+
 impl From<std::io::Error> for AnyError {
     fn from(e: std::io::Error) -> Self {
         AnyError::Io(e)
@@ -66,21 +133,57 @@ struct LoginResponse {
     loginStatus: String, // TODO enum this
 }
 
-fn get_session_token() -> Result<String, AnyError> {
-    let username = fs::read_to_string(USERFILE)?.replace("\n", "");
-    let password = fs::read_to_string(PASSFILE)?.replace("\n", "");
+/// Which TOTP/2FA provider is configured on the account. Mirrors the
+/// `TwoFactorProviderType` split in rbw's client, kept to a single variant
+/// for now since Betfair only ever asks for an authenticator-app TOTP code.
+#[derive(Debug, Clone, Copy)]
+enum TwoFactorProviderType {
+    Totp,
+}
+
+/// A two-factor step to complete during interactive login: which provider
+/// is configured, and a callback invoked to fetch the current 6-digit code
+/// if and when Betfair's `loginStatus` asks for one.
+struct TwoFactor {
+    #[allow(dead_code)]
+    provider: TwoFactorProviderType,
+    code_provider: Box<dyn Fn() -> String>,
+}
+
+/// How to authenticate with Betfair.
+enum LoginMethod {
+    /// The non-interactive cert-login flow against `identitysso-cert`,
+    /// requiring an uploaded client certificate (see the `cert` module).
+    Certificate,
+    /// The standard interactive `/api/login` endpoint used by accounts
+    /// that can't or won't upload a client certificate.
+    Interactive { two_factor: Option<TwoFactor> },
+}
+
+fn get_session_token_with(
+    config: &BotfairConfig,
+    method: &LoginMethod,
+) -> Result<String, AnyError> {
+    match method {
+        LoginMethod::Certificate => get_session_token_cert(config),
+        LoginMethod::Interactive { two_factor } => {
+            get_session_token_interactive(config, two_factor.as_ref())
+        }
+    }
+}
 
-    let proxy = reqwest::Proxy::all("socks5h://127.0.0.1:40001")?;
-    let ident =
-        Identity::from_pkcs12_der(std::fs::read(PFXFILE)?.as_slice(), "")?;
-    let cl: Client = Client::builder().identity(ident).proxy(proxy).build()?;
+fn get_session_token_cert(config: &BotfairConfig) -> Result<String, AnyError> {
+    let cl = config.http_client_with_identity()?;
 
     let appheader = format!("{}", rand::random::<u128>());
 
-    let login_request_form = LoginRequestForm { username, password };
+    let login_request_form = LoginRequestForm {
+        username: config.username.clone(),
+        password: config.password.clone(),
+    };
     info!("{:?}", login_request_form);
     let login_response: LoginResponse = cl
-        .post(CERTLOGIN_URI)
+        .post(config.region.certlogin_uri())
         .header("X-Application", appheader)
         .form(&login_request_form)
         .send()?
@@ -94,18 +197,54 @@ fn get_session_token() -> Result<String, AnyError> {
     }
 }
 
-use generated_api::{listMarketBookRequest, MarketBook, MarketId};
-use json_rpc::{RpcRequest, RpcResponse};
-fn try_lmb(
-    session_token: String,
-    market_id: MarketId,
-) -> Result<Vec<MarketBook>, AnyError> {
-    let app_key = fs::read_to_string(APPKEYFILE)?.replace("\n", "");
+/// Logs in via the standard interactive endpoint. If Betfair's
+/// `loginStatus` comes back `LF-DEVICE_AUTH_REQUIRED` and a [`TwoFactor`]
+/// was supplied, the current code is fetched from its callback and appended
+/// to the password (Betfair's own convention for submitting a 2FA code),
+/// and the login is retried exactly once.
+fn get_session_token_interactive(
+    config: &BotfairConfig,
+    two_factor: Option<&TwoFactor>,
+) -> Result<String, AnyError> {
+    let mut password = config.password.clone();
+    let cl = config.http_client()?;
+
+    let mut two_factor_submitted = false;
+    loop {
+        let login_request_form = LoginRequestForm {
+            username: config.username.clone(),
+            password: password.clone(),
+        };
+        let login_response: LoginResponse = cl
+            .post(config.region.login_uri())
+            .header("X-Application", config.app_key.clone())
+            .form(&login_request_form)
+            .send()?
+            .json()?;
 
-    let proxy = reqwest::Proxy::all("socks5h://127.0.0.1:40001")?;
-    let cl: Client = Client::builder().proxy(proxy).build()?;
+        info!("{:?}", login_response);
 
-    let method = "SportsAPING/v1.0/listMarketBook".to_owned();
+        match login_response.loginStatus.as_str() {
+            "SUCCESS" => {
+                return login_response.sessionToken.ok_or(AnyError::Other);
+            }
+            "LF-DEVICE_AUTH_REQUIRED" if !two_factor_submitted => {
+                let two_factor = two_factor.ok_or(AnyError::Other)?;
+                password.push_str(&(two_factor.code_provider)());
+                two_factor_submitted = true;
+            }
+            _ => return Err(AnyError::Other),
+        }
+    }
+}
+
+use generated_api::{listMarketBookRequest, MarketBook, MarketId};
+use session::Session;
+
+/// Fetches the current book for `market_id`, via the generic
+/// [`Session::call_rpc`] dispatcher rather than a hand-rolled method string
+/// and response type.
+fn try_lmb(session: &Session, market_id: MarketId) -> Result<Vec<MarketBook>, AnyError> {
     let params = listMarketBookRequest {
         marketIds: vec![market_id],
         priceProjection: None,
@@ -119,18 +258,7 @@ fn try_lmb(
         matchedSince: None,
         betIds: None,
     };
-    let rpc_request = RpcRequest::new(method, params);
-
-    // TODO handle exceptions
-    let rpc_response: RpcResponse<Vec<MarketBook>> = cl
-        .post(JSONRPC_URI)
-        .header("X-Application", app_key)
-        .header("X-Authentication", session_token)
-        .json(&rpc_request)
-        .send()?
-        .json()?;
-
-    Ok(rpc_response.into_inner())
+    session.call_rpc(&params)
 }
 
 fn main() -> Result<(), AnyError> {
@@ -138,12 +266,30 @@ fn main() -> Result<(), AnyError> {
         .target(env_logger::Target::Stderr)
         .init();
 
-    match get_session_token() {
-        Ok(x) => {
-            let books: Vec<MarketBook> = try_lmb(x, "1.156586178".to_owned())?;
+    let config = config::ClientBuilder::new()
+        .username(config::CredentialSource::File(
+            "/home/esotericnonsense/betfair/betfair-user".into(),
+        ))
+        .password(config::CredentialSource::File(
+            "/home/esotericnonsense/betfair/betfair-pass".into(),
+        ))
+        .app_key(config::CredentialSource::File(
+            "/home/esotericnonsense/betfair/betfair-app-key".into(),
+        ))
+        .identity(config::IdentitySource::File(
+            "/home/esotericnonsense/betfair/identity.pfx".into(),
+        ))
+        .proxy_uri("socks5h://127.0.0.1:40001".to_owned())
+        .region(config::Region::Uk)
+        .build()?;
+
+    match Session::login(config, LoginMethod::Certificate) {
+        Ok(session) => {
+            let books: Vec<MarketBook> = try_lmb(&session, "1.156586178".to_owned())?;
             info!("{:?}", books);
             let s: String = serde_json::to_string(&books).expect("whatever");
             println!("{}", s);
+            session.logout()?;
             Ok(())
         }
         Err(e) => {
@@ -0,0 +1,321 @@
+// SPDX-Copyright: Copyright (c) 2019 Daniel Edgecumbe (esotericnonsense)
+// SPDX-License-Identifier: AGPL-3.0-only
+//
+// This file is part of botfair.  botfair is free software: you can
+// redistribute it and/or modify it under the terms of the GNU Affero General
+// Public License as published by the Free Software Foundation, either version
+// 3 of the License, or (at your option) any later version.
+//
+// botfair is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE.  See the GNU Affero General Public License
+// for more details.
+//
+// You should have received a copy of the GNU Affero General Public License
+// along with botfair.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Local profit-and-loss / net-exposure calculator.
+//!
+//! `MarketProfitAndLoss`/`RunnerProfitAndLoss` (see
+//! [`crate::generated_api`]) only ever hold figures the server has already
+//! computed, via a network round-trip. [`MarketPosition`] recomputes the same
+//! matrix locally from a set of matched bets (read off `ClearedOrderSummary`
+//! or `PlaceInstructionReport` results), so a strategy can project its
+//! exposure from pending instructions before they are even placed.
+//!
+//! `ifWin`/`ifLose` assume every other selection in the market is irrelevant
+//! to the specific scenario being evaluated: `ifWin(R)` nets the whole
+//! matched book against R being the sole winner, while `ifLose(R)` only nets
+//! the bets placed on R itself, since the identity of whichever other
+//! selection actually wins isn't known from the matched bets alone.
+//! `ifPlace` follows the same scoped-to-this-runner approach as `ifLose`, but
+//! priced at the each-way place odds.
+
+#![allow(non_snake_case)]
+
+use crate::generated_api::{
+    amount_to_f64, ClearedOrderSummary, ItemDescription, MarketProfitAndLoss,
+    PlaceInstructionReport, RunnerProfitAndLoss, SelectionId, Side,
+};
+use std::collections::HashSet;
+
+/// A single matched bet, reduced to just the fields a P&L projection needs.
+#[derive(Debug, Clone)]
+pub struct MatchedBet {
+    pub selectionId: SelectionId,
+    pub side: Side,
+    pub priceMatched: f64,
+    pub sizeMatched: f64,
+}
+
+impl MatchedBet {
+    /// Reads a matched bet off a settled `ClearedOrderSummary`, if it carries
+    /// enough information to be included in a projection.
+    pub fn from_cleared_order_summary(order: &ClearedOrderSummary) -> Option<Self> {
+        Some(MatchedBet {
+            selectionId: order.selectionId?,
+            side: order.side.clone()?,
+            priceMatched: amount_to_f64(order.priceMatched?.0),
+            sizeMatched: amount_to_f64(order.sizeSettled?),
+        })
+    }
+
+    /// Reads a matched bet off the response to a `placeOrders` call, if the
+    /// instruction was (at least partially) matched.
+    pub fn from_place_instruction_report(report: &PlaceInstructionReport) -> Option<Self> {
+        Some(MatchedBet {
+            selectionId: report.instruction.selectionId,
+            side: report.instruction.side.clone(),
+            priceMatched: amount_to_f64(report.averagePriceMatched?.0),
+            sizeMatched: amount_to_f64(report.sizeMatched?),
+        })
+    }
+}
+
+/// Each-way parameters for a market, read off an `ItemDescription`: the
+/// number of runners that pay out on the place part of the book, and the
+/// divisor applied to win odds to derive place odds. `numberOfWinners` isn't
+/// used by the scoped-to-this-runner `ifPlace` approximation below (see
+/// module docs), but is kept alongside `eachWayDivisor` since both come from
+/// the same `ItemDescription` and a caller may want it for its own checks.
+#[derive(Debug, Clone, Copy)]
+pub struct EachWayTerms {
+    pub numberOfWinners: i32,
+    pub eachWayDivisor: f64,
+}
+
+/// Computes the place odds for a win price under the given each-way terms:
+/// `1 + (price - 1) / eachWayDivisor`.
+fn place_price(price: f64, terms: EachWayTerms) -> f64 {
+    1.0 + (price - 1.0) / terms.eachWayDivisor
+}
+
+/// A locally-computed net-exposure projection over a set of matched bets.
+pub struct MarketPosition {
+    commission_rate: f64,
+    each_way_terms: Option<EachWayTerms>,
+    bets: Vec<MatchedBet>,
+}
+
+impl MarketPosition {
+    /// Starts a position with no matched bets yet. `commission_rate` is the
+    /// fraction of net winnings retained by Betfair (e.g. `0.05` for 5%,
+    /// mirroring `commissionApplied` on the real `MarketProfitAndLoss`).
+    /// `each_way_terms` should be `Some` for each-way/place markets, read off
+    /// any `ItemDescription` of a bet in the market, to additionally compute
+    /// `ifPlace`.
+    pub fn new(commission_rate: f64, each_way_terms: Option<EachWayTerms>) -> Self {
+        MarketPosition {
+            commission_rate,
+            each_way_terms,
+            bets: Vec::new(),
+        }
+    }
+
+    pub fn add_bet(&mut self, bet: MatchedBet) -> &mut Self {
+        self.bets.push(bet);
+        self
+    }
+
+    pub fn add_bets<I: IntoIterator<Item = MatchedBet>>(&mut self, bets: I) -> &mut Self {
+        self.bets.extend(bets);
+        self
+    }
+
+    /// Net profit/loss applying commission to a positive result, matching
+    /// Betfair's own convention that `ifWin`/`ifLose` are already
+    /// commission-adjusted.
+    fn net_of_commission(&self, gross: f64) -> f64 {
+        if gross > 0.0 {
+            gross * (1.0 - self.commission_rate)
+        } else {
+            gross
+        }
+    }
+
+    fn if_wins(&self, selection_id: SelectionId) -> f64 {
+        let gross: f64 = self
+            .bets
+            .iter()
+            .map(|bet| {
+                let sign = if bet.selectionId == selection_id {
+                    bet.priceMatched - 1.0
+                } else {
+                    -1.0
+                };
+                let sign = match bet.side {
+                    Side::BACK => sign,
+                    Side::LAY => -sign,
+                    // A side the crate doesn't recognise can't be signed
+                    // safely; exclude it from the projection instead of
+                    // guessing.
+                    Side::Unknown(_) => 0.0,
+                };
+                sign * bet.sizeMatched
+            })
+            .sum();
+        self.net_of_commission(gross)
+    }
+
+    fn if_loses(&self, selection_id: SelectionId) -> f64 {
+        let gross: f64 = self
+            .bets
+            .iter()
+            .filter(|bet| bet.selectionId == selection_id)
+            .map(|bet| match bet.side {
+                Side::BACK => -bet.sizeMatched,
+                Side::LAY => bet.sizeMatched,
+                Side::Unknown(_) => 0.0,
+            })
+            .sum();
+        self.net_of_commission(gross)
+    }
+
+    fn if_places(&self, selection_id: SelectionId, terms: EachWayTerms) -> f64 {
+        let gross: f64 = self
+            .bets
+            .iter()
+            .filter(|bet| bet.selectionId == selection_id)
+            .map(|bet| {
+                let place_price = place_price(bet.priceMatched, terms);
+                match bet.side {
+                    Side::BACK => (place_price - 1.0) * bet.sizeMatched,
+                    Side::LAY => -(place_price - 1.0) * bet.sizeMatched,
+                    Side::Unknown(_) => 0.0,
+                }
+            })
+            .sum();
+        self.net_of_commission(gross)
+    }
+
+    /// Projects the `ifWin`/`ifLose`/`ifPlace` payoff for every selection
+    /// with at least one matched bet, as a `MarketProfitAndLoss`-compatible
+    /// structure.
+    pub fn profit_and_loss(&self, marketId: Option<String>) -> MarketProfitAndLoss {
+        let mut selections: Vec<SelectionId> = Vec::new();
+        let mut seen: HashSet<SelectionId> = HashSet::new();
+        for bet in &self.bets {
+            if seen.insert(bet.selectionId) {
+                selections.push(bet.selectionId);
+            }
+        }
+
+        let runners: Vec<RunnerProfitAndLoss> = selections
+            .into_iter()
+            .map(|selection_id| {
+                let if_place = self
+                    .each_way_terms
+                    .map(|terms| self.if_places(selection_id, terms));
+                RunnerProfitAndLoss::new(
+                    selection_id,
+                    self.if_wins(selection_id),
+                    self.if_loses(selection_id),
+                    if_place,
+                )
+            })
+            .collect();
+
+        MarketProfitAndLoss::new(marketId, Some(self.commission_rate), runners)
+    }
+}
+
+impl EachWayTerms {
+    /// Reads each-way terms off an `ItemDescription`, if both of the
+    /// required fields are present.
+    pub fn from_item_description(desc: &ItemDescription) -> Option<Self> {
+        Some(EachWayTerms {
+            numberOfWinners: desc.numberOfWinners?,
+            eachWayDivisor: desc.eachWayDivisor?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bet(selection_id: SelectionId, side: Side, price: f64, size: f64) -> MatchedBet {
+        MatchedBet {
+            selectionId: selection_id,
+            side,
+            priceMatched: price,
+            sizeMatched: size,
+        }
+    }
+
+    #[test]
+    fn back_bet_if_win_is_profit_at_odds_if_lose_is_minus_stake() {
+        let mut pos = MarketPosition::new(0.0, None);
+        pos.add_bet(bet(1, Side::BACK, 2.0, 10.0));
+
+        assert_eq!(pos.if_wins(1), 10.0); // (2.0 - 1.0) * 10.0
+        assert_eq!(pos.if_loses(1), -10.0);
+    }
+
+    #[test]
+    fn lay_bet_if_win_is_minus_liability_if_lose_is_plus_stake() {
+        let mut pos = MarketPosition::new(0.0, None);
+        pos.add_bet(bet(1, Side::LAY, 2.0, 10.0));
+
+        assert_eq!(pos.if_wins(1), -10.0); // -((2.0 - 1.0) * 10.0)
+        assert_eq!(pos.if_loses(1), 10.0);
+    }
+
+    #[test]
+    fn if_wins_nets_bets_on_other_selections_in_the_same_market() {
+        let mut pos = MarketPosition::new(0.0, None);
+        pos.add_bet(bet(1, Side::BACK, 2.0, 10.0));
+        pos.add_bet(bet(2, Side::BACK, 3.0, 10.0));
+
+        // Selection 1 winning means selection 2's back bet loses its stake.
+        assert_eq!(pos.if_wins(1), 10.0 - 10.0);
+        // Selection 2 winning means selection 1's back bet loses its stake.
+        assert_eq!(pos.if_wins(2), 20.0 - 10.0);
+    }
+
+    #[test]
+    fn if_loses_only_counts_bets_on_that_selection() {
+        let mut pos = MarketPosition::new(0.0, None);
+        pos.add_bet(bet(1, Side::BACK, 2.0, 10.0));
+        pos.add_bet(bet(2, Side::BACK, 3.0, 5.0));
+
+        assert_eq!(pos.if_loses(1), -10.0);
+    }
+
+    #[test]
+    fn commission_is_only_applied_to_a_positive_result() {
+        let mut winning = MarketPosition::new(0.1, None);
+        winning.add_bet(bet(1, Side::BACK, 2.0, 10.0));
+        assert_eq!(winning.if_wins(1), 10.0 * 0.9);
+
+        let mut losing = MarketPosition::new(0.1, None);
+        losing.add_bet(bet(1, Side::LAY, 2.0, 10.0));
+        // A loss isn't discounted by commission.
+        assert_eq!(losing.if_wins(1), -10.0);
+    }
+
+    #[test]
+    fn if_places_prices_at_the_each_way_divisor() {
+        let terms = EachWayTerms {
+            numberOfWinners: 3,
+            eachWayDivisor: 4.0,
+        };
+        let mut back = MarketPosition::new(0.0, Some(terms));
+        back.add_bet(bet(1, Side::BACK, 5.0, 10.0));
+        // place_price = 1 + (5.0 - 1.0) / 4.0 = 2.0
+        assert_eq!(back.if_places(1, terms), 10.0);
+
+        let mut lay = MarketPosition::new(0.0, Some(terms));
+        lay.add_bet(bet(1, Side::LAY, 5.0, 10.0));
+        assert_eq!(lay.if_places(1, terms), -10.0);
+    }
+
+    #[test]
+    fn unknown_side_does_not_contribute_to_the_projection() {
+        let mut pos = MarketPosition::new(0.0, None);
+        pos.add_bet(bet(1, Side::Unknown("NEW_SIDE".to_owned()), 2.0, 10.0));
+
+        assert_eq!(pos.if_wins(1), 0.0);
+        assert_eq!(pos.if_loses(1), 0.0);
+    }
+}
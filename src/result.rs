@@ -0,0 +1,90 @@
+// SPDX-Copyright: Copyright (c) 2019 Daniel Edgecumbe (esotericnonsense)
+// SPDX-License-Identifier: AGPL-3.0-only
+//
+// This file is part of botfair.  botfair is free software: you can
+// redistribute it and/or modify it under the terms of the GNU Affero General
+// Public License as published by the Free Software Foundation, either version
+// 3 of the License, or (at your option) any later version.
+//
+// botfair is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE.  See the GNU Affero General Public License
+// for more details.
+//
+// You should have received a copy of the GNU Affero General Public License
+// along with botfair.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The crate-wide error and result types.
+//!
+//! Every fallible operation on [`BFClient`](crate::BFClient) returns
+//! [`Result<T>`]. Transport-level failures (`reqwest`, I/O) are kept
+//! distinct from [`Error::APINGException`], a structured decode of the
+//! `APINGException` fault Betfair embeds in a JSON-RPC error response, so
+//! that callers can match on `errorCode` and implement their own
+//! per-code retry/back-off instead of string-matching an opaque message.
+
+use crate::generated_exceptions::errorCode;
+use crate::json_rpc::{BetfairException, RpcFault};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A decoded Betfair `APINGException`, as nested under the `data` field of
+/// a JSON-RPC error response.
+pub type ApingException = BetfairException;
+
+#[derive(Debug)]
+pub enum Error {
+    /// A transport-level failure from the underlying HTTP client.
+    Reqwest(reqwest::Error),
+    /// A local I/O failure, e.g. reading a credentials file.
+    Io(std::io::Error),
+    /// The JSON-RPC call completed but Betfair returned an `APINGException`
+    /// fault instead of a result.
+    APINGException(ApingException),
+    /// The JSON-RPC response had neither a `result` nor an `error` field.
+    JSONRPCError,
+    /// A request was attempted before a session token was available.
+    SessionTokenNotPresent,
+    /// The session token was rejected or has expired.
+    SessionTokenInvalid,
+    /// The non-interactive cert-login flow did not return a session token.
+    BFLoginFailure(String),
+    /// The keep-alive call did not report `SUCCESS`.
+    BFKeepAliveFailure(crate::client::KeepAliveError),
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Reqwest(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<RpcFault> for Error {
+    fn from(f: RpcFault) -> Self {
+        match f {
+            RpcFault::Aping(e) => Error::APINGException(e),
+            // A JSON-RPC-level fault with no nested APINGException, e.g. a
+            // malformed request; surface the raw message under `Unknown`.
+            RpcFault::Raw(message) => Error::APINGException(ApingException {
+                error_code: errorCode::Unknown,
+                request_uuid: None,
+                error_details: Some(message),
+            }),
+            RpcFault::Empty => Error::JSONRPCError,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,185 @@
+// Session lifecycle management: owns the token and the `reqwest::Client`
+// used to refresh it, so callers don't have to thread a bare token (and its
+// retry/refresh logic) through every RPC call by hand. Betfair tokens expire
+// after ~4h idle or 24h regardless, so a long-lived bot needs to call
+// `keep_alive` periodically and be ready to relogin on demand.
+
+use crate::config::BotfairConfig;
+use crate::generated_exceptions::errorCode;
+use crate::json_rpc::{RpcCall, RpcRequest, RpcResponse};
+use crate::{AnyError, LoginMethod};
+use reqwest::Client;
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::time::Instant;
+
+#[derive(Debug, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum KeepAliveError {
+    #[serde(rename = "")]
+    NONE,
+    INPUT_VALIDATION_ERROR,
+    INTERNAL_ERROR,
+    NO_SESSION,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(non_camel_case_types)]
+enum KeepAliveStatus {
+    SUCCESS,
+    FAIL,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+struct KeepAliveResponse {
+    #[allow(dead_code)]
+    token: String,
+    #[allow(dead_code)]
+    product: String,
+    status: KeepAliveStatus,
+    error: Option<KeepAliveError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(non_camel_case_types)]
+enum LogoutStatus {
+    SUCCESS,
+    FAIL,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+struct LogoutResponse {
+    status: LogoutStatus,
+    error: Option<String>,
+}
+
+/// Owns a Betfair session token plus the `reqwest::Client` and application
+/// key used to refresh/retire it. The token is behind a `RefCell` (and
+/// last-use tracking behind a `Cell`) so [`Session::call`] can transparently
+/// relogin and replace it through a shared `&Session`, without forcing every
+/// caller along the chain to take `&mut Session`.
+pub struct Session {
+    config: BotfairConfig,
+    method: LoginMethod,
+    client: Client,
+    token: RefCell<String>,
+    last_use: Cell<Instant>,
+}
+
+impl Session {
+    /// Runs `method`'s login flow against `config` and wraps the resulting
+    /// token in a `Session`, ready to hand to RPC calls.
+    pub fn login(config: BotfairConfig, method: LoginMethod) -> Result<Self, AnyError> {
+        let token = crate::get_session_token_with(&config, &method)?;
+        let client = config.http_client()?;
+        Ok(Session {
+            config,
+            method,
+            client,
+            token: RefCell::new(token),
+            last_use: Cell::new(Instant::now()),
+        })
+    }
+
+    pub fn app_key(&self) -> &str {
+        &self.config.app_key
+    }
+
+    /// A `reqwest::Client` honoring this session's configured proxy, for
+    /// making RPC calls alongside [`Session::call`].
+    pub fn http_client(&self) -> Result<Client, AnyError> {
+        self.config.http_client()
+    }
+
+    /// How long it has been since a call last went through [`Session::call`]
+    /// or [`Session::keep_alive`].
+    /// A caller running a long-lived bot should call [`Session::keep_alive`]
+    /// well before this exceeds Betfair's ~4h idle timeout.
+    pub fn idle_for(&self) -> std::time::Duration {
+        self.last_use.get().elapsed()
+    }
+
+    /// Resets the idle timer on the current token, keeping the session alive
+    /// for another ~4h without needing a real RPC call in between.
+    pub fn keep_alive(&self) -> Result<(), AnyError> {
+        let keep_alive_response: KeepAliveResponse = self
+            .client
+            .get(self.config.region.keepalive_uri())
+            .header("Accept", "application/json")
+            .header("X-Application", self.config.app_key.as_str())
+            .header("X-Authentication", self.token.borrow().as_str())
+            .send()?
+            .json()?;
+
+        self.last_use.set(Instant::now());
+        match keep_alive_response.status {
+            KeepAliveStatus::SUCCESS => Ok(()),
+            KeepAliveStatus::FAIL => Err(AnyError::SessionKeepAliveFailure(
+                keep_alive_response.error.unwrap_or(KeepAliveError::NONE),
+            )),
+        }
+    }
+
+    /// Invalidates the current token on Betfair's side. The `Session` should
+    /// be dropped afterwards; it is not usable for further calls.
+    pub fn logout(&self) -> Result<(), AnyError> {
+        let logout_response: LogoutResponse = self
+            .client
+            .get(self.config.region.logout_uri())
+            .header("Accept", "application/json")
+            .header("X-Application", self.config.app_key.as_str())
+            .header("X-Authentication", self.token.borrow().as_str())
+            .send()?
+            .json()?;
+
+        match logout_response.status {
+            LogoutStatus::SUCCESS => Ok(()),
+            LogoutStatus::FAIL => Err(AnyError::SessionLogoutFailure(
+                logout_response.error.unwrap_or_default(),
+            )),
+        }
+    }
+
+    /// Re-runs the original `LoginMethod` and replaces the cached token.
+    fn relogin(&self) -> Result<(), AnyError> {
+        let fresh = crate::get_session_token_with(&self.config, &self.method)?;
+        *self.token.borrow_mut() = fresh;
+        Ok(())
+    }
+
+    /// Runs `f` with the current token, transparently relogging in and
+    /// retrying exactly once if Betfair reports `INVALID_SESSION_INFORMATION`.
+    pub fn call<T>(&self, f: impl Fn(&str) -> Result<T, AnyError>) -> Result<T, AnyError> {
+        self.last_use.set(Instant::now());
+        match f(self.token.borrow().as_str()) {
+            Err(AnyError::BetfairException(e))
+                if e.error_code == errorCode::INVALID_SESSION_INFORMATION =>
+            {
+                self.relogin()?;
+                f(self.token.borrow().as_str())
+            }
+            other => other,
+        }
+    }
+
+    /// Dispatches a single [`RpcCall`]-implementing request, picking its
+    /// method name and response type automatically instead of requiring a
+    /// hand-written function per operation (see `generated_methods`). Header
+    /// injection and session-expiry retry are the same as [`Session::call`].
+    pub fn call_rpc<P: RpcCall>(&self, params: &P) -> Result<P::Response, AnyError> {
+        self.call(|token| {
+            let rpc_request = RpcRequest::new(P::METHOD.to_owned(), params);
+            let rpc_response: RpcResponse<P::Response> = self
+                .client
+                .post(self.config.region.jsonrpc_uri())
+                .header("X-Application", self.config.app_key.as_str())
+                .header("X-Authentication", token)
+                .json(&rpc_request)
+                .send()?
+                .json()?;
+            Ok(rpc_response.into_inner()?)
+        })
+    }
+}
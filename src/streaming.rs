@@ -0,0 +1,861 @@
+// SPDX-Copyright: Copyright (c) 2019 Daniel Edgecumbe (esotericnonsense)
+// SPDX-License-Identifier: AGPL-3.0-only
+//
+// This file is part of botfair.  botfair is free software: you can
+// redistribute it and/or modify it under the terms of the GNU Affero General
+// Public License as published by the Free Software Foundation, either version
+// 3 of the License, or (at your option) any later version.
+//
+// botfair is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE.  See the GNU Affero General Public License
+// for more details.
+//
+// You should have received a copy of the GNU Affero General Public License
+// along with botfair.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A thin client for Betfair's Exchange Stream API.
+//!
+//! Unlike the `SportsAPING` JSON-RPC surface, which must be polled, the
+//! stream API is a persistent TLS socket over which
+//! Betfair pushes newline-delimited JSON "operation" (`op`) messages. This
+//! module authenticates the socket with an existing session token, lets a
+//! caller subscribe to market and order changes for many markets at once (or
+//! all markets matching a filter), and maintains an in-memory cache per
+//! market/order that is kept up to date by applying incremental deltas as
+//! they arrive, requesting a full image on subscribe and replaying the last
+//! `initialClk`/`clk` on reconnect so Betfair resumes from there.
+
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+use native_tls::{TlsConnector, TlsStream};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const STREAM_HOST: &str = "stream-api.betfair.com";
+const STREAM_PORT: u16 = 443;
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(std::io::Error),
+    Tls(native_tls::Error),
+    HandshakeTimeout,
+    Json(serde_json::Error),
+    NotConnected,
+    AuthenticationFailed(String),
+}
+
+impl From<std::io::Error> for StreamError {
+    fn from(e: std::io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<native_tls::Error> for StreamError {
+    fn from(e: native_tls::Error) -> Self {
+        StreamError::Tls(e)
+    }
+}
+
+impl From<serde_json::Error> for StreamError {
+    fn from(e: serde_json::Error) -> Self {
+        StreamError::Json(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, StreamError>;
+
+/// A `Read` adapter over the shared, mutex-guarded TLS stream, so the reader
+/// thread can pull bytes from the single session the writer writes into
+/// instead of attempting a second handshake over a cloned socket. TLS is
+/// stateful per connection: once the writer has completed a handshake on a
+/// `TcpStream`, a second `ClientHello` cannot be layered on top of it, so the
+/// reader and writer must share one `TlsStream`, locking only for the
+/// duration of each individual read or write.
+struct SharedReader(Arc<Mutex<TlsStream<TcpStream>>>);
+
+impl Read for SharedReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0
+            .lock()
+            .expect("stream writer lock poisoned")
+            .read(buf)
+    }
+}
+
+pub type MarketId = String;
+pub type SelectionId = i64;
+pub type BetId = String;
+
+/// The streaming API's own market filter shape. It overlaps heavily with
+/// the REST `MarketFilter` but is a distinct wire type, so it is kept
+/// self-contained here rather than borrowed from the (JSON-RPC-only)
+/// generated bindings.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MarketFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marketIds: Option<Vec<MarketId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eventTypeIds: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eventIds: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countryCodes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bettingTypes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bspMarket: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub turnInPlayEnabled: Option<bool>,
+}
+
+/// Selects which fields of a market's book are pushed on the market change
+/// channel, mirroring `PriceProjection` for the REST API.
+#[derive(Debug, Clone, Serialize)]
+pub struct MarketDataFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ladderLevels: Option<u32>,
+}
+
+impl Default for MarketDataFilter {
+    fn default() -> Self {
+        MarketDataFilter {
+            fields: None,
+            ladderLevels: None,
+        }
+    }
+}
+
+/// Selects which orders/accounts the order change channel should cover.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OrderFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accountIds: Option<Vec<i64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub includeOverallPosition: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customerStrategyRefs: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partitionMatchedByStrategyRef: Option<bool>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "op")]
+#[allow(non_snake_case)]
+enum StreamRequest {
+    #[serde(rename = "authentication")]
+    Authentication {
+        id: i64,
+        session: String,
+        appKey: String,
+    },
+    #[serde(rename = "marketSubscription")]
+    MarketSubscription {
+        id: i64,
+        marketFilter: MarketFilter,
+        marketDataFilter: MarketDataFilter,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        initialClk: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        clk: Option<String>,
+    },
+    #[serde(rename = "orderSubscription")]
+    OrderSubscription { id: i64, orderFilter: OrderFilter },
+    #[serde(rename = "heartbeat")]
+    Heartbeat { id: i64 },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op")]
+enum StreamMessage {
+    #[serde(rename = "connection")]
+    Connection { connectionId: String },
+    #[serde(rename = "status")]
+    Status {
+        id: Option<i64>,
+        statusCode: String,
+        #[serde(default)]
+        connectionClosed: bool,
+        errorMessage: Option<String>,
+    },
+    #[serde(rename = "mcm")]
+    MarketChange(MarketChangeMessage),
+    #[serde(rename = "ocm")]
+    OrderChange(OrderChangeMessage),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MarketChangeMessage {
+    pub id: Option<i64>,
+    pub ct: Option<String>, // "SUB_IMAGE" | "RESUB_DELTA" | "HEARTBEAT"
+    pub clk: Option<String>,
+    pub initialClk: Option<String>,
+    pub heartbeatMs: Option<i64>,
+    pub pt: i64,
+    pub mc: Option<Vec<MarketChange>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MarketChange {
+    pub id: MarketId,
+    #[serde(default)]
+    pub img: bool,
+    pub rc: Option<Vec<RunnerChange>>,
+    pub tv: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RunnerChange {
+    pub id: SelectionId,
+    pub ltp: Option<f64>,
+    pub tv: Option<f64>,
+    pub batb: Option<Vec<(u32, f64, f64)>>,
+    pub batl: Option<Vec<(u32, f64, f64)>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OrderChangeMessage {
+    pub id: Option<i64>,
+    pub ct: Option<String>,
+    pub clk: Option<String>,
+    pub initialClk: Option<String>,
+    pub pt: i64,
+    pub oc: Option<Vec<OrderMarketChange>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OrderMarketChange {
+    pub id: MarketId,
+    pub orc: Option<Vec<OrderRunnerChange>>,
+}
+
+/// Per-runner order changes within an [`OrderMarketChange`]. `fullImage`
+/// marks `uo` as the complete set of unmatched/recently-matched orders for
+/// this runner rather than a delta, which [`apply_order_change`] uses to
+/// detect orders that have dropped off the book (fully matched-and-settled,
+/// since a cancellation is already reported via `sc`).
+#[derive(Debug, Deserialize)]
+pub struct OrderRunnerChange {
+    pub id: SelectionId,
+    #[serde(default)]
+    pub fullImage: bool,
+    pub uo: Option<Vec<UnmatchedOrder>>,
+}
+
+/// A single order, as published on the `uo` (unmatched orders) array of the
+/// order change channel. Despite the name, Betfair continues to report a bet
+/// here via `sm`/`sc` right up until it settles or is fully cancelled.
+#[derive(Debug, Deserialize)]
+pub struct UnmatchedOrder {
+    pub id: BetId,
+    pub p: f64,
+    pub s: f64,
+    pub side: String,   // "B" | "L"
+    pub status: String, // "E" (executable) | "EC" (execution complete)
+    #[serde(default)]
+    pub sm: f64, // size matched
+    #[serde(default)]
+    pub sc: f64, // size cancelled
+    #[serde(default)]
+    pub sr: f64, // size remaining
+    pub avp: Option<f64>, // average price matched
+}
+
+/// A cached, continually-updated view of a single market's runner ladders,
+/// built by folding [`MarketChange`] deltas onto the last full image.
+#[derive(Debug, Clone, Default)]
+pub struct MarketBookCache {
+    pub total_matched: Option<f64>,
+    pub runners: HashMap<SelectionId, RunnerBookCache>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RunnerBookCache {
+    pub last_traded_price: Option<f64>,
+    pub total_matched: Option<f64>,
+    pub best_available_to_back: Vec<PriceSize>,
+    pub best_available_to_lay: Vec<PriceSize>,
+}
+
+/// A single ladder level, as published on the `batb`/`batl` change arrays.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PriceSize {
+    pub price: f64,
+    pub size: f64,
+}
+
+/// A cached, continually-updated view of a single order, built by folding
+/// [`UnmatchedOrder`] updates onto the last known state. Queryable from
+/// [`StreamClient::order_book`]/[`StreamClient::order`] so a strategy can
+/// look up current execution state without polling `listCurrentOrders`.
+#[derive(Debug, Clone, Default)]
+pub struct CachedOrder {
+    pub marketId: MarketId,
+    pub selectionId: SelectionId,
+    pub side: String,
+    pub price: f64,
+    pub size: f64,
+    pub sizeMatched: f64,
+    pub sizeCancelled: f64,
+    pub averagePriceMatched: Option<f64>,
+    pub status: String,
+}
+
+/// A typed delta derived from the order change channel, keyed by `betId`
+/// rather than the raw per-runner shape of [`OrderMarketChange`].
+#[derive(Debug, Clone)]
+pub enum OrderEvent {
+    /// `sizeMatched` increased since the last update for this bet.
+    OrderMatched {
+        betId: BetId,
+        marketId: MarketId,
+        selectionId: SelectionId,
+        sizeMatched: f64,
+        averagePriceMatched: Option<f64>,
+    },
+    /// `sizeCancelled` increased since the last update for this bet.
+    OrderCancelled {
+        betId: BetId,
+        marketId: MarketId,
+        selectionId: SelectionId,
+        sizeCancelled: f64,
+    },
+    /// The bet dropped off a full-image `uo` snapshot without having just
+    /// been reported cancelled, i.e. it finished matching and has now
+    /// settled.
+    OrderSettled {
+        betId: BetId,
+        marketId: MarketId,
+        selectionId: SelectionId,
+        sizeMatched: f64,
+        averagePriceMatched: Option<f64>,
+    },
+}
+
+/// An event emitted by the stream once a change has been applied to the
+/// local cache.
+#[derive(Debug, Clone)]
+pub enum ChangeEvent {
+    MarketUpdate(MarketId, MarketBookCache),
+    Order(OrderEvent),
+    ConnectionLost,
+    Reconnected,
+}
+
+/// The `clk`/`initialClk` sequence tokens for the current market
+/// subscription, replayed on reconnect so Betfair resumes the subscription
+/// from here instead of resyncing every market from scratch.
+#[derive(Clone, Default)]
+struct ClkState {
+    initial_clk: Option<String>,
+    clk: Option<String>,
+}
+
+struct Subscription {
+    market: Option<(MarketFilter, MarketDataFilter)>,
+    order: Option<OrderFilter>,
+    clk: ClkState,
+}
+
+/// A subscription client for Betfair's Exchange Stream API.
+///
+/// Holds the TLS socket plus the subscriptions made so far (so a dropped
+/// connection can be transparently re-established and re-subscribed). The
+/// same socket is shared with a background reader thread that reads from it,
+/// applies deltas to an in-memory market/order cache and forwards
+/// [`ChangeEvent`]s to the caller over a channel.
+pub struct StreamClient {
+    writer: Arc<Mutex<TlsStream<TcpStream>>>,
+    next_id: AtomicI64,
+    subscriptions: Arc<Mutex<Subscription>>,
+    order_cache: Arc<Mutex<HashMap<BetId, CachedOrder>>>,
+    shutdown: SyncSender<()>,
+    reader_handle: Option<thread::JoinHandle<()>>,
+}
+
+impl StreamClient {
+    /// Connects to the Exchange Stream endpoint and authenticates using an
+    /// existing `SportsAPING` session token, returning the client plus a
+    /// receiver of change events.
+    pub fn connect(session_token: &str, app_key: &str) -> Result<(Self, Receiver<ChangeEvent>)> {
+        let tcp = TcpStream::connect((STREAM_HOST, STREAM_PORT))?;
+        let connector = TlsConnector::new()?;
+        let tls = connector
+            .connect(STREAM_HOST, tcp)
+            .map_err(|_| StreamError::HandshakeTimeout)?;
+
+        let writer = Arc::new(Mutex::new(tls));
+        let subscriptions = Arc::new(Mutex::new(Subscription {
+            market: None,
+            order: None,
+            clk: ClkState::default(),
+        }));
+        let order_cache = Arc::new(Mutex::new(HashMap::new()));
+
+        let (event_tx, event_rx) = mpsc::channel();
+        let (shutdown_tx, shutdown_rx) = mpsc::sync_channel(0);
+
+        let reader_handle = {
+            let writer = writer.clone();
+            let subscriptions = subscriptions.clone();
+            let order_cache = order_cache.clone();
+            let session_token = session_token.to_owned();
+            let app_key = app_key.to_owned();
+            thread::spawn(move || {
+                reader_thread(
+                    writer,
+                    subscriptions,
+                    order_cache,
+                    session_token,
+                    app_key,
+                    event_tx,
+                    shutdown_rx,
+                )
+            })
+        };
+
+        let mut client = StreamClient {
+            writer,
+            next_id: AtomicI64::new(1),
+            subscriptions,
+            order_cache,
+            shutdown: shutdown_tx,
+            reader_handle: Some(reader_handle),
+        };
+
+        client.authenticate(session_token, app_key)?;
+
+        Ok((client, event_rx))
+    }
+
+    fn next_id(&self) -> i64 {
+        self.next_id.fetch_add(1, Ordering::SeqCst)
+    }
+
+    fn send(&self, req: &StreamRequest) -> Result<()> {
+        let mut line = serde_json::to_string(req)?;
+        line.push('\n');
+        let mut writer = self.writer.lock().expect("stream writer lock poisoned");
+        writer.write_all(line.as_bytes())?;
+        Ok(())
+    }
+
+    fn authenticate(&self, session_token: &str, app_key: &str) -> Result<()> {
+        self.send(&StreamRequest::Authentication {
+            id: self.next_id(),
+            session: session_token.to_owned(),
+            appKey: app_key.to_owned(),
+        })
+    }
+
+    /// Subscribes to market changes for every market matching `filter`,
+    /// shaped by `data_filter`. Calling this again replaces the previous
+    /// market subscription (Betfair allows only one per connection) and,
+    /// on reconnect, the same subscription is transparently re-sent so the
+    /// caller doesn't have to track reconnection itself.
+    pub fn market_subscription(
+        &self,
+        filter: MarketFilter,
+        data_filter: MarketDataFilter,
+    ) -> Result<()> {
+        {
+            let mut subs = self
+                .subscriptions
+                .lock()
+                .expect("stream subscriptions lock poisoned");
+            subs.market = Some((filter.clone(), data_filter.clone()));
+            // A fresh subscribe starts a new `initialClk`/`clk` sequence; a
+            // subsequent reconnect replays whatever this accumulates.
+            subs.clk = ClkState::default();
+        }
+        self.send(&StreamRequest::MarketSubscription {
+            id: self.next_id(),
+            marketFilter: filter,
+            marketDataFilter: data_filter,
+            initialClk: None,
+            clk: None,
+        })
+    }
+
+    /// Subscribes to order/execution changes matching `filter`. As with
+    /// [`market_subscription`](Self::market_subscription), this is replayed
+    /// automatically after an automatic reconnect.
+    pub fn order_subscription(&self, filter: OrderFilter) -> Result<()> {
+        {
+            let mut subs = self
+                .subscriptions
+                .lock()
+                .expect("stream subscriptions lock poisoned");
+            subs.order = Some(filter.clone());
+        }
+        self.send(&StreamRequest::OrderSubscription {
+            id: self.next_id(),
+            orderFilter: filter,
+        })
+    }
+
+    /// A snapshot of every order currently tracked by the order cache,
+    /// keyed by `betId`.
+    pub fn order_book(&self) -> HashMap<BetId, CachedOrder> {
+        self.order_cache
+            .lock()
+            .expect("stream order cache lock poisoned")
+            .clone()
+    }
+
+    /// The cached state of a single order, if it has been seen on the order
+    /// change channel and hasn't since settled.
+    pub fn order(&self, bet_id: &str) -> Option<CachedOrder> {
+        self.order_cache
+            .lock()
+            .expect("stream order cache lock poisoned")
+            .get(bet_id)
+            .cloned()
+    }
+
+    /// Signals the background reader thread to stop and drops the socket.
+    pub fn shutdown(mut self) {
+        let _ = self.shutdown.send(());
+        if let Some(handle) = self.reader_handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for StreamClient {
+    fn drop(&mut self) {
+        let _ = self.shutdown.send(());
+    }
+}
+
+fn reader_thread(
+    writer: Arc<Mutex<TlsStream<TcpStream>>>,
+    subscriptions: Arc<Mutex<Subscription>>,
+    order_cache: Arc<Mutex<HashMap<BetId, CachedOrder>>>,
+    session_token: String,
+    app_key: String,
+    events: mpsc::Sender<ChangeEvent>,
+    shutdown: Receiver<()>,
+) {
+    let mut cache: HashMap<MarketId, MarketBookCache> = HashMap::new();
+
+    loop {
+        if shutdown.try_recv().is_ok() {
+            trace!("stream: shutdown signal caught, exiting reader thread");
+            return;
+        }
+
+        let result = read_loop(
+            &writer,
+            &session_token,
+            &app_key,
+            &events,
+            &mut cache,
+            &order_cache,
+            &subscriptions,
+        );
+
+        match result {
+            Ok(()) => return, // socket closed cleanly
+            Err(e) => {
+                warn!("stream: connection lost: {:?}, reconnecting", e);
+                let _ = events.send(ChangeEvent::ConnectionLost);
+            }
+        }
+
+        if shutdown.try_recv().is_ok() {
+            return;
+        }
+
+        // Reconnect-with-resubscribe: replace the socket inside `writer`
+        // and re-issue the last market/order subscription, if any.
+        thread::sleep(Duration::from_millis(1000));
+        match reconnect(&writer, &session_token, &app_key, &subscriptions) {
+            Ok(()) => {
+                let _ = events.send(ChangeEvent::Reconnected);
+            }
+            Err(e) => {
+                error!("stream: reconnect failed: {:?}", e);
+            }
+        }
+    }
+}
+
+fn reconnect(
+    writer: &Arc<Mutex<TlsStream<TcpStream>>>,
+    session_token: &str,
+    app_key: &str,
+    subscriptions: &Arc<Mutex<Subscription>>,
+) -> Result<()> {
+    let tcp = TcpStream::connect((STREAM_HOST, STREAM_PORT))?;
+    let connector = TlsConnector::new()?;
+    let tls = connector
+        .connect(STREAM_HOST, tcp)
+        .map_err(|_| StreamError::HandshakeTimeout)?;
+
+    {
+        let mut guard = writer.lock().expect("stream writer lock poisoned");
+        *guard = tls;
+    }
+
+    let send = |req: &StreamRequest| -> Result<()> {
+        let mut line = serde_json::to_string(req)?;
+        line.push('\n');
+        let mut guard = writer.lock().expect("stream writer lock poisoned");
+        guard.write_all(line.as_bytes())?;
+        Ok(())
+    };
+
+    send(&StreamRequest::Authentication {
+        id: 0,
+        session: session_token.to_owned(),
+        appKey: app_key.to_owned(),
+    })?;
+
+    let subs = subscriptions
+        .lock()
+        .expect("stream subscriptions lock poisoned");
+    if let Some((filter, data_filter)) = subs.market.clone() {
+        // Replay the last `initialClk`/`clk` tokens so Betfair resumes this
+        // subscription instead of resending a full image for every market.
+        send(&StreamRequest::MarketSubscription {
+            id: 1,
+            marketFilter: filter,
+            marketDataFilter: data_filter,
+            initialClk: subs.clk.initial_clk.clone(),
+            clk: subs.clk.clk.clone(),
+        })?;
+    }
+    if let Some(filter) = subs.order.clone() {
+        send(&StreamRequest::OrderSubscription {
+            id: 2,
+            orderFilter: filter,
+        })?;
+    }
+
+    Ok(())
+}
+
+fn read_loop(
+    writer: &Arc<Mutex<TlsStream<TcpStream>>>,
+    _session_token: &str,
+    _app_key: &str,
+    events: &mpsc::Sender<ChangeEvent>,
+    cache: &mut HashMap<MarketId, MarketBookCache>,
+    order_cache: &Arc<Mutex<HashMap<BetId, CachedOrder>>>,
+    subscriptions: &Arc<Mutex<Subscription>>,
+) -> Result<()> {
+    let mut reader = BufReader::new(SharedReader(writer.clone()));
+
+    let mut line = String::new();
+    loop {
+        line.clear();
+        let n = reader.read_line(&mut line)?;
+        if n == 0 {
+            return Ok(()); // EOF: remote closed the socket
+        }
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let message: StreamMessage = serde_json::from_str(trimmed)?;
+        match message {
+            StreamMessage::Connection { connectionId } => {
+                debug!("stream: connected, connectionId={}", connectionId);
+            }
+            StreamMessage::Status {
+                statusCode,
+                connectionClosed,
+                errorMessage,
+                ..
+            } => {
+                debug!("stream: status {} (closed={:?})", statusCode, errorMessage);
+                if connectionClosed {
+                    return Err(StreamError::AuthenticationFailed(
+                        errorMessage.unwrap_or_default(),
+                    ));
+                }
+            }
+            StreamMessage::MarketChange(mcm) => {
+                // Track the sequence tokens so a dropped connection can
+                // resubscribe from here instead of resyncing from scratch.
+                {
+                    let mut subs = subscriptions
+                        .lock()
+                        .expect("stream subscriptions lock poisoned");
+                    if subs.clk.initial_clk.is_none() {
+                        subs.clk.initial_clk = mcm.initialClk.clone();
+                    }
+                    if mcm.clk.is_some() {
+                        subs.clk.clk = mcm.clk.clone();
+                    }
+                }
+
+                for change in mcm.mc.into_iter().flatten() {
+                    apply_market_change(cache, change, &events);
+                }
+            }
+            StreamMessage::OrderChange(ocm) => {
+                for change in ocm.oc.into_iter().flatten() {
+                    apply_order_change(order_cache, change, &events);
+                }
+            }
+        }
+    }
+}
+
+fn apply_market_change(
+    cache: &mut HashMap<MarketId, MarketBookCache>,
+    change: MarketChange,
+    events: &mpsc::Sender<ChangeEvent>,
+) {
+    let entry = cache
+        .entry(change.id.clone())
+        .or_insert_with(MarketBookCache::default);
+
+    if change.img {
+        *entry = MarketBookCache::default();
+    }
+
+    if let Some(tv) = change.tv {
+        entry.total_matched = Some(tv);
+    }
+
+    for rc in change.rc.into_iter().flatten() {
+        let runner = entry
+            .runners
+            .entry(rc.id)
+            .or_insert_with(RunnerBookCache::default);
+        if let Some(ltp) = rc.ltp {
+            runner.last_traded_price = Some(ltp);
+        }
+        if let Some(tv) = rc.tv {
+            runner.total_matched = Some(tv);
+        }
+        if let Some(batb) = rc.batb {
+            for (level, price, size) in batb {
+                set_ladder_level(&mut runner.best_available_to_back, level, price, size);
+            }
+        }
+        if let Some(batl) = rc.batl {
+            for (level, price, size) in batl {
+                set_ladder_level(&mut runner.best_available_to_lay, level, price, size);
+            }
+        }
+    }
+
+    let _ = events.send(ChangeEvent::MarketUpdate(change.id, entry.clone()));
+}
+
+fn apply_order_change(
+    order_cache: &Arc<Mutex<HashMap<BetId, CachedOrder>>>,
+    change: OrderMarketChange,
+    events: &mpsc::Sender<ChangeEvent>,
+) {
+    let market_id = change.id;
+
+    for runner in change.orc.into_iter().flatten() {
+        let selection_id = runner.id;
+        let mut seen: Vec<BetId> = Vec::new();
+
+        for order in runner.uo.into_iter().flatten() {
+            seen.push(order.id.clone());
+
+            let (matched_delta, cancelled_delta) = {
+                let mut cache = order_cache
+                    .lock()
+                    .expect("stream order cache lock poisoned");
+                let prev = cache.get(&order.id);
+                let matched_delta = order.sm - prev.map(|p| p.sizeMatched).unwrap_or(0.0);
+                let cancelled_delta = order.sc - prev.map(|p| p.sizeCancelled).unwrap_or(0.0);
+                cache.insert(
+                    order.id.clone(),
+                    CachedOrder {
+                        marketId: market_id.clone(),
+                        selectionId: selection_id,
+                        side: order.side.clone(),
+                        price: order.p,
+                        size: order.s,
+                        sizeMatched: order.sm,
+                        sizeCancelled: order.sc,
+                        averagePriceMatched: order.avp,
+                        status: order.status.clone(),
+                    },
+                );
+                (matched_delta, cancelled_delta)
+            };
+
+            if matched_delta > 0.0 {
+                let _ = events.send(ChangeEvent::Order(OrderEvent::OrderMatched {
+                    betId: order.id.clone(),
+                    marketId: market_id.clone(),
+                    selectionId: selection_id,
+                    sizeMatched: order.sm,
+                    averagePriceMatched: order.avp,
+                }));
+            }
+            if cancelled_delta > 0.0 {
+                let _ = events.send(ChangeEvent::Order(OrderEvent::OrderCancelled {
+                    betId: order.id.clone(),
+                    marketId: market_id.clone(),
+                    selectionId: selection_id,
+                    sizeCancelled: order.sc,
+                }));
+            }
+        }
+
+        if runner.fullImage {
+            let settled: Vec<(BetId, CachedOrder)> = {
+                let mut cache = order_cache
+                    .lock()
+                    .expect("stream order cache lock poisoned");
+                let settled_ids: Vec<BetId> = cache
+                    .iter()
+                    .filter(|(bet_id, cached)| {
+                        cached.marketId == market_id
+                            && cached.selectionId == selection_id
+                            && !seen.contains(bet_id)
+                    })
+                    .map(|(bet_id, _)| bet_id.clone())
+                    .collect();
+                settled_ids
+                    .into_iter()
+                    .filter_map(|bet_id| cache.remove(&bet_id).map(|cached| (bet_id, cached)))
+                    .collect()
+            };
+
+            for (bet_id, cached) in settled {
+                let _ = events.send(ChangeEvent::Order(OrderEvent::OrderSettled {
+                    betId: bet_id,
+                    marketId: cached.marketId,
+                    selectionId: cached.selectionId,
+                    sizeMatched: cached.sizeMatched,
+                    averagePriceMatched: cached.averagePriceMatched,
+                }));
+            }
+        }
+    }
+}
+
+fn set_ladder_level(ladder: &mut Vec<PriceSize>, level: u32, price: f64, size: f64) {
+    let level = level as usize;
+    while ladder.len() <= level {
+        ladder.push(PriceSize {
+            price: 0.0,
+            size: 0.0,
+        });
+    }
+    ladder[level] = PriceSize { price, size };
+}